@@ -0,0 +1,76 @@
+//! Romanizes a large generated corpus and reports the throughput.
+//!
+//! Run with `cargo bench`.
+
+use std::time::Instant;
+
+use hangeul::{romanize, Pronouncer, Word};
+
+/// Builds a corpus of pseudo-random words of 1 to 5 syllables.
+fn corpus(words: usize) -> String {
+    let mut state: u32 = 0x2545_f491;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state
+    };
+
+    let mut corpus = String::new();
+
+    for _ in 0..words {
+        for _ in 0..=next() % 5 {
+            let c = std::char::from_u32(0xac00 + next() % 11172).unwrap();
+            corpus.push(c);
+        }
+
+        corpus.push(if next() % 8 == 0 { '\n' } else { ' ' });
+    }
+
+    corpus
+}
+
+fn main() {
+    // Warm up.
+    romanize(&corpus(10_000));
+
+    let corpus = corpus(1_000_000);
+    let syllables = corpus.chars().filter(|c| !c.is_whitespace()).count();
+
+    let start = Instant::now();
+    let output = romanize(&corpus);
+    let elapsed = start.elapsed();
+
+    println!(
+        "romanized {} syllables ({} bytes) in {:.3} s ({:.1} M syllables/s)",
+        syllables,
+        output.len(),
+        elapsed.as_secs_f64(),
+        syllables as f64 / elapsed.as_secs_f64() / 1e6
+    );
+
+    let mut words: Vec<_> = corpus
+        .split_whitespace()
+        .map(|w| Word::new(w).unwrap())
+        .collect();
+
+    for &strict in &[false, true] {
+        let pronouncer = Pronouncer::new(strict);
+
+        let start = Instant::now();
+
+        for word in &mut words {
+            pronouncer.pronounce_in_place(word);
+        }
+
+        let elapsed = start.elapsed();
+
+        println!(
+            "pronounced {} words (strict = {}) in {:.3} s ({:.1} M syllables/s)",
+            words.len(),
+            strict,
+            elapsed.as_secs_f64(),
+            syllables as f64 / elapsed.as_secs_f64() / 1e6
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use std::ops::Range;
+
+use crate::{Lexer, RevisedRomanization, Token, Transcriber};
+
+/// A piece of romanized text and the source text it was romanized from
+///
+/// Ranges are relative to the input of [`romanize_aligned`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AlignedSegment {
+    text: String,
+    source: Range<usize>,
+    chars: Range<usize>,
+}
+
+impl AlignedSegment {
+    /// Returns the romanized text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the byte range of the source text.
+    pub fn source(&self) -> Range<usize> {
+        self.source.clone()
+    }
+
+    /// Returns the character range of the source text.
+    pub fn chars(&self) -> Range<usize> {
+        self.chars.clone()
+    }
+}
+
+/// Romanizes text using Revised Romanization rules, aligned to the input.
+///
+/// See [`romanize_aligned_with`].
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::romanize_aligned;
+///
+/// let segments = romanize_aligned("신라 가자");
+/// let texts: Vec<_> = segments.iter().map(|s| s.text()).collect();
+/// assert_eq!(texts, ["sil", "la", " ", "ga", "ja"]);
+/// assert_eq!(segments[1].source(), 3..6);
+/// assert_eq!(segments[1].chars(), 1..2);
+/// ```
+pub fn romanize_aligned(input: &str) -> Vec<AlignedSegment> {
+    romanize_aligned_with(input, &RevisedRomanization)
+}
+
+/// Romanizes text using the given transcriber, aligned to the input.
+///
+/// The texts of the returned segments concatenate to the output of
+/// [`romanize_with`](crate::romanize_with), and their source ranges cover
+/// the input in order, without overlapping.
+///
+/// Text that is not Hangeul is a single segment, copied as is. Otherwise,
+/// each syllable is a segment of its own, holding what the transcriber
+/// writes for the word up to and including the syllable, less what it
+/// writes before it. Changes in pronunciation are thus attributed to the
+/// syllable whose pronunciation changed, e.g., 한국어 => han|gu|geo, and a
+/// doubled ㄹ is split between the syllables (신라 => sil|la).
+///
+/// Some output cannot be attributed to a single syllable, i.e., when the
+/// transcription of the start of a word is not the start of the
+/// transcription of the whole word, such as with rules for the last
+/// syllable. In that case, the syllable is merged with the next one, and
+/// the segment spans both syllables.
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::{romanize_aligned_with, Popular};
+///
+/// // ㅣ is "ee" only at the end of a word.
+/// let segments = romanize_aligned_with("지민 지", &Popular::new());
+/// let texts: Vec<_> = segments.iter().map(|s| s.text()).collect();
+/// assert_eq!(texts, ["jimin", " ", "jee"]);
+/// assert_eq!(segments[0].chars(), 0..2);
+/// ```
+pub fn romanize_aligned_with<T>(input: &str, transcriber: &T) -> Vec<AlignedSegment>
+where
+    T: Transcriber + ?Sized,
+{
+    let mut segments = Vec::new();
+
+    let mut offset = 0;
+    let mut char_offset = 0;
+
+    for token in Lexer::new(input.chars()) {
+        match token {
+            Token::Word(word) => {
+                let pieces = word.romanize_syllables_with(transcriber);
+                let last = pieces.len() - 1;
+
+                let mut text = String::new();
+                let mut start = (offset, char_offset);
+
+                for (i, ((_, _, piece), c)) in
+                    pieces.into_iter().zip(word.as_ref().chars()).enumerate()
+                {
+                    offset += c.len_utf8();
+                    char_offset += 1;
+
+                    text.push_str(&piece);
+
+                    if !piece.is_empty() || i == last {
+                        segments.push(AlignedSegment {
+                            text,
+                            source: start.0..offset,
+                            chars: start.1..char_offset,
+                        });
+
+                        text = String::new();
+                        start = (offset, char_offset);
+                    }
+                }
+            }
+            Token::Any(s) => {
+                let source = offset..offset + s.len();
+                let chars = char_offset..char_offset + s.chars().count();
+
+                offset = source.end;
+                char_offset = chars.end;
+
+                segments.push(AlignedSegment {
+                    text: s,
+                    source,
+                    chars,
+                });
+            }
+        }
+    }
+
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{romanize_aligned, romanize_aligned_with};
+    use crate::{romanize_with, McCuneReischauer, Popular, Transcriber, Yale};
+
+    fn texts(input: &str) -> Vec<String> {
+        romanize_aligned(input)
+            .into_iter()
+            .map(|s| s.text().to_string())
+            .collect()
+    }
+
+    fn assert_consistent<T: Transcriber>(input: &str, transcriber: &T) {
+        let segments = romanize_aligned_with(input, transcriber);
+
+        let text: String = segments.iter().map(|s| s.text()).collect();
+        assert_eq!(text, romanize_with(input, transcriber));
+
+        let mut offset = 0;
+        let mut char_offset = 0;
+
+        for segment in &segments {
+            assert_eq!(segment.source().start, offset);
+            assert_eq!(segment.chars().start, char_offset);
+            assert_eq!(
+                input[segment.source()].chars().count(),
+                segment.chars().len()
+            );
+            offset = segment.source().end;
+            char_offset = segment.chars().end;
+        }
+
+        assert_eq!(offset, input.len());
+    }
+
+    #[test]
+    fn test_romanize_aligned() {
+        assert_eq!(texts("한국어"), ["han", "gu", "geo"]);
+        assert_eq!(texts("종로"), ["jong", "no"]);
+        assert_eq!(texts("좋고"), ["jo", "ko"]);
+        assert_eq!(texts("(볼빨간)"), ["(", "bol", "ppal", "gan", ")"]);
+        assert!(texts("").is_empty());
+    }
+
+    #[test]
+    fn test_romanize_aligned_with_merged_syllables() {
+        let segments = romanize_aligned_with("너무 좋아", &Popular::new());
+        let texts: Vec<_> = segments.iter().map(|s| s.text()).collect();
+        assert_eq!(texts, ["numoo", " ", "jo", "a"]);
+
+        let segments = romanize_aligned_with("하니", &Popular::new());
+        let texts: Vec<_> = segments.iter().map(|s| s.text()).collect();
+        assert_eq!(texts, ["ha", "nee"]);
+
+        let segments = romanize_aligned_with("이", &Popular::new());
+        assert_eq!(segments[0].text(), "lee");
+    }
+
+    #[test]
+    fn test_consistency() {
+        let inputs = [
+            "한국어",
+            "신라의 달밤",
+            "IU - 밤편지",
+            "없어요, 괜찮아!",
+            "우주 소녀 지민 이",
+        ];
+
+        for input in &inputs {
+            assert_consistent(input, &crate::RevisedRomanization);
+            assert_consistent(input, &McCuneReischauer::new());
+            assert_consistent(input, &Yale::new());
+            assert_consistent(input, &Popular::new());
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{Choseong, Jongseong, Jungseong};
+
+pub fn transliterate_initial_consonant(j: Choseong, voiced: bool) -> &'static str {
+    match j {
+        Choseong::Giyeok if voiced => "g",
+        Choseong::Giyeok => "k",
+        Choseong::SsangGiyeok => "kk",
+        Choseong::Nieun => "n",
+        Choseong::Digeut if voiced => "d",
+        Choseong::Digeut => "t",
+        Choseong::SsangDigeut => "tt",
+        Choseong::Rieul => "r",
+        Choseong::Mieum => "m",
+        Choseong::Bieup if voiced => "b",
+        Choseong::Bieup => "p",
+        Choseong::SsangBieup => "pp",
+        Choseong::Siot => "s",
+        Choseong::SsangSiot => "ss",
+        Choseong::Ieung => "",
+        Choseong::Jieut if voiced => "j",
+        Choseong::Jieut => "ch",
+        Choseong::SsangJieut => "jj",
+        Choseong::Chieut => "chh",
+        Choseong::Kieuk => "kh",
+        Choseong::Tieut => "th",
+        Choseong::Pieup => "ph",
+        Choseong::Hieut => "h",
+    }
+}
+
+pub fn transliterate_medial_vowel(j: Jungseong) -> &'static str {
+    match j {
+        Jungseong::A => "a",
+        Jungseong::Ae => "ae",
+        Jungseong::Ya => "ya",
+        Jungseong::Yae => "yae",
+        Jungseong::Eo => "ŏ",
+        Jungseong::E => "e",
+        Jungseong::Yeo => "yŏ",
+        Jungseong::Ye => "ye",
+        Jungseong::O => "o",
+        Jungseong::Wa => "wa",
+        Jungseong::Wae => "wae",
+        Jungseong::Oe => "oe",
+        Jungseong::Yo => "yo",
+        Jungseong::U => "u",
+        Jungseong::Wo => "wŏ",
+        Jungseong::We => "we",
+        Jungseong::Wi => "wi",
+        Jungseong::Yu => "yu",
+        Jungseong::Eu => "ŭ",
+        Jungseong::Ui => "ŭi",
+        Jungseong::I => "i",
+    }
+}
+
+pub fn transliterate_final_consonant(j: Jongseong) -> &'static str {
+    match j {
+        Jongseong::Giyeok
+        | Jongseong::SsangGiyeok
+        | Jongseong::GiyeokSiot
+        | Jongseong::RieulGiyeok
+        | Jongseong::Kieuk => "k",
+        Jongseong::Nieun | Jongseong::NieunJieut | Jongseong::NieunHieut => "n",
+        Jongseong::Digeut
+        | Jongseong::Siot
+        | Jongseong::SsangSiot
+        | Jongseong::Jieut
+        | Jongseong::Chieut
+        | Jongseong::Tieut
+        | Jongseong::Hieut => "t",
+        Jongseong::Rieul
+        | Jongseong::RieulBieup
+        | Jongseong::RieulSiot
+        | Jongseong::RieulTieut
+        | Jongseong::RieulHieut => "l",
+        Jongseong::Mieum | Jongseong::RieulMieum => "m",
+        Jongseong::Bieup | Jongseong::RieulPieup | Jongseong::BieupSiot | Jongseong::Pieup => "p",
+        Jongseong::Ieung => "ng",
+    }
+}
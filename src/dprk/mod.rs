@@ -0,0 +1,102 @@
+mod jamo;
+
+use self::jamo::*;
+use crate::mccune_reischauer::is_voiced;
+use crate::{Choseong, Context, Jongseong, Syllable, Transcriber};
+
+/// Romanization of Korean (DPRK, 1992)
+///
+/// The official system of North Korea is derived from McCune–Reischauer.
+/// It keeps the breves (ŏ, ŭ) and the same voicing of plain consonants but
+/// writes aspiration with an h (kh, th, ph, chh) instead of an apostrophe,
+/// writes ㅉ as jj instead of tch, and it uses no apostrophe to separate
+/// syllables.
+///
+/// Since spelling in North Korea does not follow the initial sound law
+/// (두음법칙), word-initial ㄹ and ㄴ are kept as r and n.
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::Dprk;
+///
+/// let dprk = Dprk::new();
+/// assert_eq!(dprk.romanize("평양"), "phyŏngyang");
+/// assert_eq!(dprk.romanize("로동신문"), "rodongsinmun");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Dprk;
+
+impl Dprk {
+    pub fn new() -> Dprk {
+        Dprk
+    }
+
+    /// Romanizes text using the DPRK rules.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hangeul::Dprk;
+    ///
+    /// let dprk = Dprk::new();
+    /// assert_eq!(dprk.romanize("조선"), "chosŏn");
+    /// assert_eq!(dprk.romanize("청진"), "chhŏngjin");
+    /// ```
+    pub fn romanize(&self, input: &str) -> String {
+        crate::romanize_with(input, self)
+    }
+}
+
+impl Transcriber for Dprk {
+    fn transcribe(&self, syllables: &[Syllable], _: &Context) -> String {
+        let mut res = String::new();
+
+        for (i, s) in syllables.iter().enumerate() {
+            let (j, k, m) = s.decompose();
+
+            let prev = i.checked_sub(1).map(|h| syllables[h].jongseong());
+
+            if prev == Some(Some(Jongseong::Rieul)) && j == Choseong::Rieul {
+                res.push('l');
+            } else {
+                res.push_str(transliterate_initial_consonant(j, is_voiced(prev, j)));
+            }
+
+            res.push_str(transliterate_medial_vowel(k));
+
+            if let Some(m) = m {
+                res.push_str(transliterate_final_consonant(m));
+            }
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Dprk;
+
+    fn r(s: &str) -> String {
+        Dprk::new().romanize(s)
+    }
+
+    #[test]
+    fn test_romanize() {
+        assert_eq!(r("평양"), "phyŏngyang");
+        assert_eq!(r("청진"), "chhŏngjin");
+        assert_eq!(r("개성"), "kaesŏng");
+        assert_eq!(r("한국어"), "hangugŏ");
+        assert_eq!(r("김치"), "kimchhi");
+        assert_eq!(r("짜다"), "jjada");
+        assert_eq!(r("신의주"), "sinŭiju");
+    }
+
+    #[test]
+    fn test_romanize_without_initial_sound_law() {
+        assert_eq!(r("로동신문"), "rodongsinmun");
+        assert_eq!(r("리"), "ri");
+        assert_eq!(r("녀자"), "nyŏja");
+    }
+}
@@ -0,0 +1,53 @@
+use std::error;
+use std::fmt;
+
+/// An error returned when text is not made of Hangeul syllables
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::{Error, Syllable};
+///
+/// let e = Syllable::from_char('a').unwrap_err();
+/// assert_eq!(e, Error::InvalidSyllable { position: 0, character: 'a' });
+/// assert_eq!(e.to_string(), "byte 0: invalid Hangeul syllable 'a'");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The character at byte offset `position` is not a Hangeul syllable
+    /// (U+AC00-U+D7A3).
+    InvalidSyllable { position: usize, character: char },
+}
+
+impl Error {
+    /// Returns the byte offset of the offending character.
+    pub fn position(&self) -> usize {
+        match self {
+            Error::InvalidSyllable { position, .. } => *position,
+        }
+    }
+
+    /// Returns the offending character.
+    pub fn character(&self) -> char {
+        match self {
+            Error::InvalidSyllable { character, .. } => *character,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InvalidSyllable {
+                position,
+                character,
+            } => write!(
+                f,
+                "byte {}: invalid Hangeul syllable {:?}",
+                position, character
+            ),
+        }
+    }
+}
+
+impl error::Error for Error {}
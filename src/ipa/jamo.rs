@@ -0,0 +1,123 @@
+use crate::{Choseong, Jongseong, Jungseong};
+
+pub fn transliterate_initial_consonant(j: Choseong) -> &'static str {
+    match j {
+        Choseong::Giyeok => "k",
+        Choseong::SsangGiyeok => "k͈",
+        Choseong::Nieun => "n",
+        Choseong::Digeut => "t",
+        Choseong::SsangDigeut => "t͈",
+        Choseong::Rieul => "l",
+        Choseong::Mieum => "m",
+        Choseong::Bieup => "p",
+        Choseong::SsangBieup => "p͈",
+        Choseong::Siot => "s",
+        Choseong::SsangSiot => "s͈",
+        Choseong::Ieung => "",
+        Choseong::Jieut => "tɕ",
+        Choseong::SsangJieut => "tɕ͈",
+        Choseong::Chieut => "tɕʰ",
+        Choseong::Kieuk => "kʰ",
+        Choseong::Tieut => "tʰ",
+        Choseong::Pieup => "pʰ",
+        Choseong::Hieut => "h",
+    }
+}
+
+/// Returns the allophone of an initial consonant in the given environment,
+/// or `None` if it is the same as the phoneme.
+pub fn realize_initial_consonant(
+    j: Choseong,
+    voiced: bool,
+    intervocalic: bool,
+    palatal: bool,
+) -> Option<&'static str> {
+    match j {
+        Choseong::Giyeok if voiced => Some("ɡ"),
+        Choseong::Digeut if voiced => Some("d"),
+        Choseong::Bieup if voiced => Some("b"),
+        Choseong::Jieut if voiced => Some("dʑ"),
+        Choseong::Rieul if intervocalic => Some("ɾ"),
+        Choseong::Siot if palatal => Some("ɕ"),
+        Choseong::SsangSiot if palatal => Some("ɕ͈"),
+        Choseong::Hieut if palatal => Some("ç"),
+        _ => None,
+    }
+}
+
+pub fn transliterate_medial_vowel(k: Jungseong) -> &'static str {
+    match k {
+        Jungseong::A => "a",
+        Jungseong::Ae => "ɛ",
+        Jungseong::Ya => "ja",
+        Jungseong::Yae => "jɛ",
+        Jungseong::Eo => "ʌ",
+        Jungseong::E => "e",
+        Jungseong::Yeo => "jʌ",
+        Jungseong::Ye => "je",
+        Jungseong::O => "o",
+        Jungseong::Wa => "wa",
+        Jungseong::Wae => "wɛ",
+        Jungseong::Oe => "ø",
+        Jungseong::Yo => "jo",
+        Jungseong::U => "u",
+        Jungseong::Wo => "wʌ",
+        Jungseong::We => "we",
+        Jungseong::Wi => "ɥi",
+        Jungseong::Yu => "ju",
+        Jungseong::Eu => "ɯ",
+        Jungseong::Ui => "ɰi",
+        Jungseong::I => "i",
+    }
+}
+
+pub fn transliterate_final_consonant(m: Jongseong, narrow: bool) -> &'static str {
+    match m {
+        Jongseong::Giyeok
+        | Jongseong::SsangGiyeok
+        | Jongseong::GiyeokSiot
+        | Jongseong::RieulGiyeok
+        | Jongseong::Kieuk
+            if narrow =>
+        {
+            "k̚"
+        }
+        Jongseong::Giyeok
+        | Jongseong::SsangGiyeok
+        | Jongseong::GiyeokSiot
+        | Jongseong::RieulGiyeok
+        | Jongseong::Kieuk => "k",
+        Jongseong::Nieun | Jongseong::NieunJieut | Jongseong::NieunHieut => "n",
+        Jongseong::Digeut
+        | Jongseong::Siot
+        | Jongseong::SsangSiot
+        | Jongseong::Jieut
+        | Jongseong::Chieut
+        | Jongseong::Tieut
+        | Jongseong::Hieut
+            if narrow =>
+        {
+            "t̚"
+        }
+        Jongseong::Digeut
+        | Jongseong::Siot
+        | Jongseong::SsangSiot
+        | Jongseong::Jieut
+        | Jongseong::Chieut
+        | Jongseong::Tieut
+        | Jongseong::Hieut => "t",
+        Jongseong::Rieul
+        | Jongseong::RieulBieup
+        | Jongseong::RieulSiot
+        | Jongseong::RieulTieut
+        | Jongseong::RieulHieut => "l",
+        Jongseong::Mieum | Jongseong::RieulMieum => "m",
+        Jongseong::Bieup | Jongseong::RieulPieup | Jongseong::BieupSiot | Jongseong::Pieup
+            if narrow =>
+        {
+            "p̚"
+        }
+        Jongseong::Bieup | Jongseong::RieulPieup | Jongseong::BieupSiot | Jongseong::Pieup => "p",
+        Jongseong::Ieung => "ŋ",
+    }
+}
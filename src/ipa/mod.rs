@@ -0,0 +1,192 @@
+mod jamo;
+
+use self::jamo::*;
+use crate::{Context, Jongseong, Jungseong, Pronouncer, Syllable, Transcriber};
+
+/// International Phonetic Alphabet (IPA) transcription
+///
+/// The IPA transcription is built on the strict standard pronunciation, so
+/// tensification and vowel simplification are reflected (국밥 => kukp͈ap).
+/// Tensification after verb stems is not, since it depends on the part of
+/// speech (한국어 => hankukʌ, not hank͈ukʌ).
+///
+/// A broad transcription writes phonemes only. A narrow transcription also
+/// writes the following allophones:
+///
+///   * ㄹ is `ɾ` between vowels and `l` elsewhere.
+///   * ㅅ and ㅆ are `ɕ` and `ɕ͈` before ㅣ or y.
+///   * ㅎ is `ç` before ㅣ or y.
+///   * Lax stops and affricates (ㄱ, ㄷ, ㅂ, ㅈ) are voiced between voiced
+///     sounds.
+///   * Final stops are unreleased (k̚, t̚, p̚).
+///
+/// Outputs are not enclosed in brackets or slashes.
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::Ipa;
+///
+/// assert_eq!(Ipa::broad().romanize("국물"), "kuŋmul");
+/// assert_eq!(Ipa::broad().romanize("참"), "tɕʰam");
+/// assert_eq!(Ipa::broad().romanize("바다"), "pata");
+/// assert_eq!(Ipa::narrow().romanize("바다"), "pada");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Ipa {
+    narrow: bool,
+}
+
+impl Ipa {
+    /// Creates a transcriber for broad (phonemic) transcriptions.
+    pub fn broad() -> Ipa {
+        Ipa { narrow: false }
+    }
+
+    /// Creates a transcriber for narrow (phonetic) transcriptions.
+    pub fn narrow() -> Ipa {
+        Ipa { narrow: true }
+    }
+
+    /// Transcribes text to IPA.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hangeul::Ipa;
+    ///
+    /// let ipa = Ipa::narrow();
+    /// assert_eq!(ipa.romanize("사랑"), "saɾaŋ");
+    /// assert_eq!(ipa.romanize("시간"), "ɕiɡan");
+    /// assert_eq!(ipa.romanize("국밥"), "kuk̚p͈ap̚");
+    /// ```
+    pub fn romanize(&self, input: &str) -> String {
+        crate::romanize_with(input, self)
+    }
+}
+
+impl Default for Ipa {
+    fn default() -> Ipa {
+        Ipa::broad()
+    }
+}
+
+impl Transcriber for Ipa {
+    fn pronouncer(&self) -> Option<Pronouncer> {
+        Some(Pronouncer::phonetic())
+    }
+
+    fn transcribe(&self, syllables: &[Syllable], _: &Context) -> String {
+        let mut res = String::new();
+
+        for (i, s) in syllables.iter().enumerate() {
+            let (j, k, m) = s.decompose();
+
+            let prev = i.checked_sub(1).map(|h| syllables[h].jongseong());
+
+            let initial = if self.narrow {
+                let voiced = match prev {
+                    None => false,
+                    Some(None) => true,
+                    Some(Some(m)) => is_sonorant(m),
+                };
+
+                realize_initial_consonant(j, voiced, prev == Some(None), is_palatal(k))
+                    .unwrap_or_else(|| transliterate_initial_consonant(j))
+            } else {
+                transliterate_initial_consonant(j)
+            };
+
+            res.push_str(initial);
+            res.push_str(transliterate_medial_vowel(k));
+
+            if let Some(m) = m {
+                res.push_str(transliterate_final_consonant(m, self.narrow));
+            }
+        }
+
+        res
+    }
+}
+
+fn is_sonorant(m: Jongseong) -> bool {
+    matches!(
+        m,
+        Jongseong::Nieun | Jongseong::Rieul | Jongseong::Mieum | Jongseong::Ieung
+    )
+}
+
+/// Returns whether a vowel starts with [i] or [j].
+fn is_palatal(k: Jungseong) -> bool {
+    matches!(
+        k,
+        Jungseong::Ya
+            | Jungseong::Yae
+            | Jungseong::Yeo
+            | Jungseong::Ye
+            | Jungseong::Yo
+            | Jungseong::Yu
+            | Jungseong::I
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ipa;
+
+    fn broad(s: &str) -> String {
+        Ipa::broad().romanize(s)
+    }
+
+    fn narrow(s: &str) -> String {
+        Ipa::narrow().romanize(s)
+    }
+
+    #[test]
+    fn test_broad() {
+        assert_eq!(broad("국물"), "kuŋmul");
+        assert_eq!(broad("참"), "tɕʰam");
+        assert_eq!(broad("신라"), "silla");
+        assert_eq!(broad("국밥"), "kukp͈ap");
+        assert_eq!(broad("시간"), "sikan");
+        assert_eq!(broad("의사"), "ɰisa");
+    }
+
+    #[test]
+    fn test_tensification() {
+        assert_eq!(broad("국밥"), "kukp͈ap");
+        assert_eq!(narrow("국밥"), "kuk̚p͈ap̚");
+        assert_eq!(broad("한국어"), "hankukʌ");
+        assert_eq!(narrow("한국어"), "hanɡuɡʌ");
+        assert_eq!(broad("신고"), "sinko");
+        assert_eq!(broad("안다"), "anta");
+    }
+
+    #[test]
+    fn test_liquids() {
+        assert_eq!(narrow("사랑"), "saɾaŋ");
+        assert_eq!(narrow("신라"), "ɕilla");
+        assert_eq!(narrow("물"), "mul");
+        assert_eq!(narrow("라면"), "lamjʌn");
+    }
+
+    #[test]
+    fn test_palatalization() {
+        assert_eq!(narrow("시"), "ɕi");
+        assert_eq!(narrow("쉬"), "sɥi");
+        assert_eq!(narrow("씨"), "ɕ͈i");
+        assert_eq!(narrow("셔츠"), "ɕjʌtɕʰɯ");
+        assert_eq!(narrow("힘"), "çim");
+        assert_eq!(narrow("하나"), "hana");
+    }
+
+    #[test]
+    fn test_voicing() {
+        assert_eq!(narrow("바다"), "pada");
+        assert_eq!(narrow("공부"), "koŋbu");
+        assert_eq!(narrow("불고기"), "pulɡoɡi");
+        assert_eq!(narrow("아버지"), "abʌdʑi");
+        assert_eq!(narrow("국밥"), "kuk̚p͈ap̚");
+        assert_eq!(narrow("밥"), "pap̚");
+    }
+}
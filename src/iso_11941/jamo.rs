@@ -0,0 +1,92 @@
+use crate::{Choseong, Jongseong, Jungseong};
+
+pub fn transliterate_initial_consonant(j: Choseong, north: bool) -> &'static str {
+    match j {
+        Choseong::Giyeok => "k",
+        Choseong::SsangGiyeok => "kk",
+        Choseong::Nieun => "n",
+        Choseong::Digeut => "t",
+        Choseong::SsangDigeut => "tt",
+        Choseong::Rieul => "r",
+        Choseong::Mieum => "m",
+        Choseong::Bieup => "p",
+        Choseong::SsangBieup => "pp",
+        Choseong::Siot => "s",
+        Choseong::SsangSiot => "ss",
+        Choseong::Ieung => "",
+        Choseong::Jieut if north => "j",
+        Choseong::Jieut => "c",
+        Choseong::SsangJieut if north => "jj",
+        Choseong::SsangJieut => "cc",
+        Choseong::Chieut => "ch",
+        Choseong::Kieuk => "kh",
+        Choseong::Tieut => "th",
+        Choseong::Pieup => "ph",
+        Choseong::Hieut => "h",
+    }
+}
+
+pub fn transliterate_medial_vowel(j: Jungseong, north: bool) -> &'static str {
+    match j {
+        Jungseong::A => "a",
+        Jungseong::Ae => "ae",
+        Jungseong::Ya => "ya",
+        Jungseong::Yae => "yae",
+        Jungseong::Eo if north => "ŏ",
+        Jungseong::Eo => "eo",
+        Jungseong::E => "e",
+        Jungseong::Yeo if north => "yŏ",
+        Jungseong::Yeo => "yeo",
+        Jungseong::Ye => "ye",
+        Jungseong::O => "o",
+        Jungseong::Wa => "wa",
+        Jungseong::Wae => "wae",
+        Jungseong::Oe => "oe",
+        Jungseong::Yo => "yo",
+        Jungseong::U => "u",
+        Jungseong::Wo if north => "wŏ",
+        Jungseong::Wo => "weo",
+        Jungseong::We => "we",
+        Jungseong::Wi => "wi",
+        Jungseong::Yu => "yu",
+        Jungseong::Eu if north => "ŭ",
+        Jungseong::Eu => "eu",
+        Jungseong::Ui if north => "ŭi",
+        Jungseong::Ui => "yi",
+        Jungseong::I => "i",
+    }
+}
+
+pub fn transliterate_final_consonant(j: Jongseong, north: bool) -> &'static str {
+    match j {
+        Jongseong::Giyeok => "k",
+        Jongseong::SsangGiyeok => "kk",
+        Jongseong::GiyeokSiot => "ks",
+        Jongseong::Nieun => "n",
+        Jongseong::NieunJieut if north => "nj",
+        Jongseong::NieunJieut => "nc",
+        Jongseong::NieunHieut => "nh",
+        Jongseong::Digeut => "t",
+        Jongseong::Rieul => "l",
+        Jongseong::RieulGiyeok => "lk",
+        Jongseong::RieulMieum => "lm",
+        Jongseong::RieulBieup => "lp",
+        Jongseong::RieulSiot => "ls",
+        Jongseong::RieulTieut => "lth",
+        Jongseong::RieulPieup => "lph",
+        Jongseong::RieulHieut => "lh",
+        Jongseong::Mieum => "m",
+        Jongseong::Bieup => "p",
+        Jongseong::BieupSiot => "ps",
+        Jongseong::Siot => "s",
+        Jongseong::SsangSiot => "ss",
+        Jongseong::Ieung => "ng",
+        Jongseong::Jieut if north => "j",
+        Jongseong::Jieut => "c",
+        Jongseong::Chieut => "ch",
+        Jongseong::Kieuk => "kh",
+        Jongseong::Tieut => "th",
+        Jongseong::Pieup => "ph",
+        Jongseong::Hieut => "h",
+    }
+}
@@ -0,0 +1,139 @@
+mod jamo;
+
+use self::jamo::*;
+use crate::{Choseong, Context, Jongseong, Jungseong, Pronouncer, Syllable, Transcriber};
+
+/// ISO/TR 11941:1996 romanization
+///
+/// ISO/TR 11941 is a transliteration: it follows spelling rather than
+/// pronunciation. It has two variants, one for the Democratic People's
+/// Republic of Korea (North) and one for the Republic of Korea (South),
+/// which differ mostly in the spelling of ㅓ, ㅡ, and ㅈ.
+///
+/// Initial ㄹ is written r, except after a final ㄹ, which together are
+/// written ll. A hyphen separates syllables when the reading would otherwise
+/// be ambiguous: before a vowel following a final consonant (kuk-eo) or when
+/// two vowels could be read as one.
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::Iso11941;
+///
+/// assert_eq!(Iso11941::south().romanize("한국어"), "hankuk-eo");
+/// assert_eq!(Iso11941::north().romanize("조선말"), "josŏnmal");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Iso11941 {
+    north: bool,
+}
+
+impl Iso11941 {
+    /// Creates the variant used in the Democratic People's Republic of Korea.
+    pub fn north() -> Iso11941 {
+        Iso11941 { north: true }
+    }
+
+    /// Creates the variant used in the Republic of Korea.
+    pub fn south() -> Iso11941 {
+        Iso11941 { north: false }
+    }
+
+    /// Romanizes text using ISO/TR 11941 rules.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hangeul::Iso11941;
+    ///
+    /// let iso = Iso11941::south();
+    /// assert_eq!(iso.romanize("국물"), "kukmul");
+    /// assert_eq!(iso.romanize("않다"), "anhta");
+    /// ```
+    pub fn romanize(&self, input: &str) -> String {
+        crate::romanize_with(input, self)
+    }
+
+    /// Returns whether two adjacent vowels could be read differently, either
+    /// as a single vowel or split at another point.
+    fn is_ambiguous_vowel_pair(&self, a: &str, b: &str) -> bool {
+        let is_vowel = |s: &str| {
+            Jungseong::ALL
+                .iter()
+                .any(|&k| transliterate_medial_vowel(k, self.north) == s)
+        };
+        let joined = format!("{}{}", a, b);
+
+        is_vowel(&joined)
+            || joined
+                .char_indices()
+                .skip(1)
+                .filter(|&(i, _)| i != a.len())
+                .any(|(i, _)| is_vowel(&joined[..i]) && is_vowel(&joined[i..]))
+    }
+}
+
+impl Transcriber for Iso11941 {
+    fn pronouncer(&self) -> Option<Pronouncer> {
+        None
+    }
+
+    fn transcribe(&self, syllables: &[Syllable], _: &Context) -> String {
+        let mut res = String::new();
+
+        // The vowel and final consonant of the previous syllable.
+        let mut prev: Option<(&str, Option<Jongseong>)> = None;
+
+        for s in syllables {
+            let (j, k, m) = s.decompose();
+            let vowel = transliterate_medial_vowel(k, self.north);
+
+            match prev {
+                Some((_, Some(Jongseong::Rieul))) if j == Choseong::Rieul => res.push('l'),
+                Some((_, Some(_))) if j == Choseong::Ieung => res.push('-'),
+                Some((prev_vowel, None)) if j == Choseong::Ieung => {
+                    if self.is_ambiguous_vowel_pair(prev_vowel, vowel) {
+                        res.push('-');
+                    }
+                }
+                _ => res.push_str(transliterate_initial_consonant(j, self.north)),
+            }
+
+            res.push_str(vowel);
+
+            if let Some(m) = m {
+                res.push_str(transliterate_final_consonant(m, self.north));
+            }
+
+            prev = Some((vowel, m));
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Iso11941;
+
+    #[test]
+    fn test_south() {
+        let iso = Iso11941::south();
+        assert_eq!(iso.romanize("서울"), "seoul");
+        assert_eq!(iso.romanize("의정부"), "yiceongpu");
+        assert_eq!(iso.romanize("값"), "kaps");
+        assert_eq!(iso.romanize("실라"), "silla");
+        assert_eq!(iso.romanize("한국어"), "hankuk-eo");
+        assert_eq!(iso.romanize("에오"), "e-o");
+        assert_eq!(iso.romanize("아이"), "ai");
+    }
+
+    #[test]
+    fn test_north() {
+        let iso = Iso11941::north();
+        assert_eq!(iso.romanize("서울"), "sŏul");
+        assert_eq!(iso.romanize("의정부"), "ŭijŏngpu");
+        assert_eq!(iso.romanize("앉다"), "anjta");
+        assert_eq!(iso.romanize("로동"), "rotong");
+    }
+}
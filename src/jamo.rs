@@ -0,0 +1,471 @@
+// @see https://www.unicode.org/charts/PDF/U3130.pdf
+// @see https://www.unicode.org/charts/PDF/U1100.pdf
+
+/// An initial consonant
+///
+/// Variants are in Unicode order and named after the letters in Revised
+/// Romanization.
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::Choseong;
+///
+/// assert_eq!(Choseong::from_compat('ㄲ'), Some(Choseong::SsangGiyeok));
+/// assert_eq!(Choseong::SsangGiyeok.to_compat(), 'ㄲ');
+/// assert_eq!(Choseong::SsangGiyeok.to_conjoining(), '\u{1101}');
+/// assert_eq!(Choseong::SsangGiyeok.index(), 1);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Choseong {
+    Giyeok,
+    SsangGiyeok,
+    Nieun,
+    Digeut,
+    SsangDigeut,
+    Rieul,
+    Mieum,
+    Bieup,
+    SsangBieup,
+    Siot,
+    SsangSiot,
+    Ieung,
+    Jieut,
+    SsangJieut,
+    Chieut,
+    Kieuk,
+    Tieut,
+    Pieup,
+    Hieut,
+}
+
+static CHOSEONG_COMPATS: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ',
+    'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+const CHOSEONGS_OFFSET: u32 = 0x1100;
+
+impl Choseong {
+    /// All initial consonants, in Unicode order.
+    pub const ALL: [Choseong; 19] = [
+        Choseong::Giyeok,
+        Choseong::SsangGiyeok,
+        Choseong::Nieun,
+        Choseong::Digeut,
+        Choseong::SsangDigeut,
+        Choseong::Rieul,
+        Choseong::Mieum,
+        Choseong::Bieup,
+        Choseong::SsangBieup,
+        Choseong::Siot,
+        Choseong::SsangSiot,
+        Choseong::Ieung,
+        Choseong::Jieut,
+        Choseong::SsangJieut,
+        Choseong::Chieut,
+        Choseong::Kieuk,
+        Choseong::Tieut,
+        Choseong::Pieup,
+        Choseong::Hieut,
+    ];
+
+    /// Returns the initial consonant at the given index in Unicode order.
+    pub const fn from_index(i: usize) -> Option<Choseong> {
+        if i < Choseong::ALL.len() {
+            Some(Choseong::ALL[i])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the index of the initial consonant in Unicode order.
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the initial consonant of a compatibility jamo (U+3131-U+318E),
+    /// e.g., ㄱ.
+    pub const fn from_compat(c: char) -> Option<Choseong> {
+        match position(&CHOSEONG_COMPATS, c) {
+            Some(i) => Choseong::from_index(i),
+            None => None,
+        }
+    }
+
+    /// Returns the compatibility jamo of the initial consonant.
+    pub const fn to_compat(self) -> char {
+        CHOSEONG_COMPATS[self as usize]
+    }
+
+    /// Returns the initial consonant of a conjoining jamo (U+1100-U+1112).
+    pub const fn from_conjoining(c: char) -> Option<Choseong> {
+        match (c as u32).checked_sub(CHOSEONGS_OFFSET) {
+            Some(i) => Choseong::from_index(i as usize),
+            None => None,
+        }
+    }
+
+    /// Returns the conjoining jamo of the initial consonant.
+    pub const fn to_conjoining(self) -> char {
+        to_char(CHOSEONGS_OFFSET + self as u32)
+    }
+
+    /// Returns the final consonant written with the same letter, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hangeul::{Choseong, Jongseong};
+    ///
+    /// assert_eq!(Choseong::Giyeok.to_jongseong(), Some(Jongseong::Giyeok));
+    /// assert_eq!(Choseong::SsangDigeut.to_jongseong(), None);
+    /// ```
+    pub const fn to_jongseong(self) -> Option<Jongseong> {
+        Jongseong::from_compat(self.to_compat())
+    }
+}
+
+/// A medial vowel
+///
+/// Variants are in Unicode order and named after the letters in Revised
+/// Romanization.
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::Jungseong;
+///
+/// assert_eq!(Jungseong::from_compat('ㅢ'), Some(Jungseong::Ui));
+/// assert_eq!(Jungseong::Ui.to_compat(), 'ㅢ');
+/// assert_eq!(Jungseong::Ui.to_conjoining(), '\u{1174}');
+/// assert_eq!(Jungseong::Ui.index(), 19);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Jungseong {
+    A,
+    Ae,
+    Ya,
+    Yae,
+    Eo,
+    E,
+    Yeo,
+    Ye,
+    O,
+    Wa,
+    Wae,
+    Oe,
+    Yo,
+    U,
+    Wo,
+    We,
+    Wi,
+    Yu,
+    Eu,
+    Ui,
+    I,
+}
+
+const JUNGSEONG_COMPATS_OFFSET: u32 = 0x314f;
+const JUNGSEONGS_OFFSET: u32 = 0x1161;
+
+impl Jungseong {
+    /// All medial vowels, in Unicode order.
+    pub const ALL: [Jungseong; 21] = [
+        Jungseong::A,
+        Jungseong::Ae,
+        Jungseong::Ya,
+        Jungseong::Yae,
+        Jungseong::Eo,
+        Jungseong::E,
+        Jungseong::Yeo,
+        Jungseong::Ye,
+        Jungseong::O,
+        Jungseong::Wa,
+        Jungseong::Wae,
+        Jungseong::Oe,
+        Jungseong::Yo,
+        Jungseong::U,
+        Jungseong::Wo,
+        Jungseong::We,
+        Jungseong::Wi,
+        Jungseong::Yu,
+        Jungseong::Eu,
+        Jungseong::Ui,
+        Jungseong::I,
+    ];
+
+    /// Returns the medial vowel at the given index in Unicode order.
+    pub const fn from_index(i: usize) -> Option<Jungseong> {
+        if i < Jungseong::ALL.len() {
+            Some(Jungseong::ALL[i])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the index of the medial vowel in Unicode order.
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the medial vowel of a compatibility jamo (U+314F-U+3163),
+    /// e.g., ㅏ.
+    pub const fn from_compat(c: char) -> Option<Jungseong> {
+        match (c as u32).checked_sub(JUNGSEONG_COMPATS_OFFSET) {
+            Some(i) => Jungseong::from_index(i as usize),
+            None => None,
+        }
+    }
+
+    /// Returns the compatibility jamo of the medial vowel.
+    pub const fn to_compat(self) -> char {
+        to_char(JUNGSEONG_COMPATS_OFFSET + self as u32)
+    }
+
+    /// Returns the medial vowel of a conjoining jamo (U+1161-U+1175).
+    pub const fn from_conjoining(c: char) -> Option<Jungseong> {
+        match (c as u32).checked_sub(JUNGSEONGS_OFFSET) {
+            Some(i) => Jungseong::from_index(i as usize),
+            None => None,
+        }
+    }
+
+    /// Returns the conjoining jamo of the medial vowel.
+    pub const fn to_conjoining(self) -> char {
+        to_char(JUNGSEONGS_OFFSET + self as u32)
+    }
+}
+
+/// A final consonant
+///
+/// Variants are in Unicode order and named after the letters in Revised
+/// Romanization. Consonant clusters are named after both letters, e.g.,
+/// `GiyeokSiot` for ㄳ.
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::Jongseong;
+///
+/// assert_eq!(Jongseong::from_compat('ㄳ'), Some(Jongseong::GiyeokSiot));
+/// assert_eq!(Jongseong::GiyeokSiot.to_compat(), 'ㄳ');
+/// assert_eq!(Jongseong::GiyeokSiot.to_conjoining(), '\u{11aa}');
+/// assert_eq!(Jongseong::GiyeokSiot.index(), 2);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Jongseong {
+    Giyeok,
+    SsangGiyeok,
+    GiyeokSiot,
+    Nieun,
+    NieunJieut,
+    NieunHieut,
+    Digeut,
+    Rieul,
+    RieulGiyeok,
+    RieulMieum,
+    RieulBieup,
+    RieulSiot,
+    RieulTieut,
+    RieulPieup,
+    RieulHieut,
+    Mieum,
+    Bieup,
+    BieupSiot,
+    Siot,
+    SsangSiot,
+    Ieung,
+    Jieut,
+    Chieut,
+    Kieuk,
+    Tieut,
+    Pieup,
+    Hieut,
+}
+
+static JONGSEONG_COMPATS: [char; 27] = [
+    'ㄱ', 'ㄲ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ', 'ㅁ',
+    'ㅂ', 'ㅄ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+const JONGSEONGS_OFFSET: u32 = 0x11a8;
+
+impl Jongseong {
+    /// All final consonants, in Unicode order.
+    pub const ALL: [Jongseong; 27] = [
+        Jongseong::Giyeok,
+        Jongseong::SsangGiyeok,
+        Jongseong::GiyeokSiot,
+        Jongseong::Nieun,
+        Jongseong::NieunJieut,
+        Jongseong::NieunHieut,
+        Jongseong::Digeut,
+        Jongseong::Rieul,
+        Jongseong::RieulGiyeok,
+        Jongseong::RieulMieum,
+        Jongseong::RieulBieup,
+        Jongseong::RieulSiot,
+        Jongseong::RieulTieut,
+        Jongseong::RieulPieup,
+        Jongseong::RieulHieut,
+        Jongseong::Mieum,
+        Jongseong::Bieup,
+        Jongseong::BieupSiot,
+        Jongseong::Siot,
+        Jongseong::SsangSiot,
+        Jongseong::Ieung,
+        Jongseong::Jieut,
+        Jongseong::Chieut,
+        Jongseong::Kieuk,
+        Jongseong::Tieut,
+        Jongseong::Pieup,
+        Jongseong::Hieut,
+    ];
+
+    /// Returns the final consonant at the given index in Unicode order.
+    pub const fn from_index(i: usize) -> Option<Jongseong> {
+        if i < Jongseong::ALL.len() {
+            Some(Jongseong::ALL[i])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the index of the final consonant in Unicode order.
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the final consonant of a compatibility jamo (U+3131-U+314E),
+    /// e.g., ㄳ.
+    pub const fn from_compat(c: char) -> Option<Jongseong> {
+        match position(&JONGSEONG_COMPATS, c) {
+            Some(i) => Jongseong::from_index(i),
+            None => None,
+        }
+    }
+
+    /// Returns the compatibility jamo of the final consonant.
+    pub const fn to_compat(self) -> char {
+        JONGSEONG_COMPATS[self as usize]
+    }
+
+    /// Returns the final consonant of a conjoining jamo (U+11A8-U+11C2).
+    pub const fn from_conjoining(c: char) -> Option<Jongseong> {
+        match (c as u32).checked_sub(JONGSEONGS_OFFSET) {
+            Some(i) => Jongseong::from_index(i as usize),
+            None => None,
+        }
+    }
+
+    /// Returns the conjoining jamo of the final consonant.
+    pub const fn to_conjoining(self) -> char {
+        to_char(JONGSEONGS_OFFSET + self as u32)
+    }
+
+    /// Returns the initial consonant written with the same letter, if any.
+    ///
+    /// Consonant clusters have none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hangeul::{Choseong, Jongseong};
+    ///
+    /// assert_eq!(Jongseong::Siot.to_choseong(), Some(Choseong::Siot));
+    /// assert_eq!(Jongseong::BieupSiot.to_choseong(), None);
+    /// ```
+    pub const fn to_choseong(self) -> Option<Choseong> {
+        Choseong::from_compat(self.to_compat())
+    }
+}
+
+const fn position(chars: &[char], c: char) -> Option<usize> {
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i] == c {
+            return Some(i);
+        }
+
+        i += 1;
+    }
+
+    None
+}
+
+/// Converts a code point known to be a valid character.
+pub(crate) const fn to_char(u: u32) -> char {
+    match char::from_u32(u) {
+        Some(c) => c,
+        None => char::REPLACEMENT_CHARACTER,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_choseong_conversions() {
+        for (i, &j) in Choseong::ALL.iter().enumerate() {
+            assert_eq!(j.index(), i);
+            assert_eq!(Choseong::from_index(i), Some(j));
+            assert_eq!(Choseong::from_compat(j.to_compat()), Some(j));
+            assert_eq!(Choseong::from_conjoining(j.to_conjoining()), Some(j));
+        }
+
+        assert_eq!(Choseong::from_index(19), None);
+        assert_eq!(Choseong::from_compat('ㄳ'), None);
+        assert_eq!(Choseong::from_compat('ㅏ'), None);
+        assert_eq!(Choseong::from_conjoining('\u{1113}'), None);
+        assert_eq!(Choseong::Hieut.to_conjoining(), '\u{1112}');
+    }
+
+    #[test]
+    fn test_jungseong_conversions() {
+        for (i, &k) in Jungseong::ALL.iter().enumerate() {
+            assert_eq!(k.index(), i);
+            assert_eq!(Jungseong::from_index(i), Some(k));
+            assert_eq!(Jungseong::from_compat(k.to_compat()), Some(k));
+            assert_eq!(Jungseong::from_conjoining(k.to_conjoining()), Some(k));
+        }
+
+        assert_eq!(Jungseong::A.to_compat(), 'ㅏ');
+        assert_eq!(Jungseong::I.to_compat(), 'ㅣ');
+        assert_eq!(Jungseong::from_compat('ㅎ'), None);
+        assert_eq!(Jungseong::from_compat('ㅤ'), None);
+        assert_eq!(Jungseong::from_conjoining('\u{1176}'), None);
+    }
+
+    #[test]
+    fn test_jongseong_conversions() {
+        for (i, &m) in Jongseong::ALL.iter().enumerate() {
+            assert_eq!(m.index(), i);
+            assert_eq!(Jongseong::from_index(i), Some(m));
+            assert_eq!(Jongseong::from_compat(m.to_compat()), Some(m));
+            assert_eq!(Jongseong::from_conjoining(m.to_conjoining()), Some(m));
+        }
+
+        assert_eq!(Jongseong::from_compat('ㄸ'), None);
+        assert_eq!(Jongseong::from_conjoining('\u{11a7}'), None);
+        assert_eq!(Jongseong::Hieut.to_conjoining(), '\u{11c2}');
+    }
+
+    #[test]
+    fn test_to_choseong_and_to_jongseong() {
+        for &m in Jongseong::ALL.iter() {
+            if let Some(j) = m.to_choseong() {
+                assert_eq!(j.to_jongseong(), Some(m));
+            }
+        }
+
+        let clusters = Jongseong::ALL
+            .iter()
+            .filter(|m| m.to_choseong().is_none())
+            .count();
+
+        assert_eq!(clusters, 11);
+    }
+}
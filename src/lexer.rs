@@ -2,12 +2,49 @@ use std::iter::Peekable;
 
 use crate::{Syllable, Word};
 
+/// A run of text produced by [`Lexer`]
 #[derive(Debug)]
 pub enum Token {
     Any(String),
     Word(Word),
 }
 
+/// Splits text into runs of Hangeul syllables (`true`) and runs of any other
+/// text (`false`), like [`Lexer`] but without allocating.
+pub(crate) fn runs(input: &str) -> impl Iterator<Item = (bool, &str)> {
+    let mut rest = input;
+
+    std::iter::from_fn(move || {
+        let is_word = Syllable::is_valid(rest.chars().next()?);
+
+        let end = rest
+            .find(|c| Syllable::is_valid(c) != is_word)
+            .unwrap_or(rest.len());
+
+        let (run, tail) = rest.split_at(end);
+        rest = tail;
+
+        Some((is_word, run))
+    })
+}
+
+/// Splits text into words of Hangeul syllables and runs of any other text.
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::{Lexer, Token};
+///
+/// let tokens: Vec<_> = Lexer::new("IU - 밤편지".chars()).collect();
+///
+/// match &tokens[..] {
+///     [Token::Any(s), Token::Word(word)] => {
+///         assert_eq!(s, "IU - ");
+///         assert_eq!(word.to_string(), "밤편지");
+///     }
+///     _ => panic!("unexpected tokens"),
+/// }
+/// ```
 pub struct Lexer<I: Iterator<Item = char>> {
     it: Peekable<I>,
 }
@@ -20,20 +57,16 @@ where
         Lexer { it: it.peekable() }
     }
 
-    fn take_while_is_hangeul(&mut self) -> Option<String> {
-        let mut res = String::new();
+    fn take_while_is_hangeul(&mut self) -> Option<Word> {
+        let mut res = Vec::new();
 
-        while let Some(&c) = self.it.peek() {
-            if Syllable::is_valid(c) {
-                res.push(c);
-                self.it.next();
-            } else {
-                break;
-            }
+        while let Some(s) = self.it.peek().and_then(|&c| Syllable::from_char(c).ok()) {
+            res.push(s);
+            self.it.next();
         }
 
         if !res.is_empty() {
-            Some(res)
+            Some(Word::from(res))
         } else {
             None
         }
@@ -69,10 +102,8 @@ where
         if self.it.peek().is_some() {
             if let Some(s) = self.take_while_is_not_hangeul() {
                 Some(Token::Any(s))
-            } else if let Some(s) = self.take_while_is_hangeul() {
-                Some(Token::Word(Word::new(s)))
             } else {
-                None
+                self.take_while_is_hangeul().map(Token::Word)
             }
         } else {
             None
@@ -1,12 +1,58 @@
+use std::fmt;
+
+mod alignment;
+mod dprk;
+mod error;
+mod ipa;
+mod iso_11941;
+mod jamo;
 mod lexer;
+mod markup;
+mod mccune_reischauer;
+mod phoneme;
+mod popular;
 mod pronunciation;
+mod reverse;
 mod revised_romanization;
+mod romanizer;
+mod ruby;
+mod scheme;
+mod search;
+mod ssml;
+mod stream;
+mod subtitle;
 mod syllable;
+mod transcriber;
 mod word;
+mod yale;
 
-use crate::lexer::{Lexer, Token};
+pub use crate::alignment::{romanize_aligned, romanize_aligned_with, AlignedSegment};
+pub use crate::dprk::Dprk;
+pub use crate::error::Error;
+pub use crate::ipa::Ipa;
+pub use crate::iso_11941::Iso11941;
+pub use crate::jamo::{Choseong, Jongseong, Jungseong};
+pub use crate::lexer::{Lexer, Token};
+pub use crate::markup::{
+    romanize_html, romanize_html_with, romanize_markdown, romanize_markdown_with,
+};
+pub use crate::mccune_reischauer::McCuneReischauer;
+pub use crate::phoneme::{PhoneSet, Phoneme, PhonemicSyllable};
+pub use crate::popular::Popular;
+pub use crate::pronunciation::Pronouncer;
+pub use crate::reverse::{hangeulize, Candidate, Hangeulizer};
+pub use crate::revised_romanization::RevisedRomanization;
+pub use crate::romanizer::{romanize_batch, CacheStats, Romanizer};
+pub use crate::ruby::Ruby;
+pub use crate::scheme::{ParseError, Scheme};
+pub use crate::search::{Hit, SearchIndex};
+pub use crate::ssml::Ssml;
+pub use crate::stream::{romanize_stream, romanize_stream_with};
+pub use crate::subtitle::{ParseSubtitleFormatError, SubtitleFormat, Subtitles};
 pub use crate::syllable::Syllable;
-pub use crate::word::Word;
+pub use crate::transcriber::{Context, Transcriber};
+pub use crate::word::{SyllablesMut, Word};
+pub use crate::yale::Yale;
 
 /// Romanizes text using Revised Romanization rules.
 ///
@@ -20,10 +66,93 @@ pub use crate::word::Word;
 /// assert_eq!(romanize("MOMOLAND - 뿜뿜"), "MOMOLAND - ppumppum");
 /// ```
 pub fn romanize(input: &str) -> String {
-    Lexer::new(input.chars())
-        .map(|token| match token {
-            Token::Word(word) => word.romanize(),
-            Token::Any(s) => s,
-        })
-        .collect()
+    romanize_with(input, &RevisedRomanization)
+}
+
+/// Romanizes text using the given transcriber.
+///
+/// Only words are transcribed; all other text is left as is.
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::{romanize_with, McCuneReischauer, Yale};
+///
+/// assert_eq!(romanize_with("부산 2", &McCuneReischauer::new()), "pusan 2");
+/// assert_eq!(romanize_with("부산 2", &Yale::new()), "pusan 2");
+/// ```
+pub fn romanize_with<T>(input: &str, transcriber: &T) -> String
+where
+    T: Transcriber + ?Sized,
+{
+    let mut res = String::with_capacity(input.len());
+    // Writing to a `String` cannot fail.
+    let _ = romanize_into_with(input, &mut res, transcriber);
+    res
+}
+
+/// Romanizes text using Revised Romanization rules, writing the result to
+/// `dst`.
+///
+/// Text that is not Hangeul is written as is, without allocating.
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::romanize_into;
+///
+/// let mut s = String::from("> ");
+/// romanize_into("여보세요", &mut s)?;
+/// assert_eq!(s, "> yeoboseyo");
+/// # Ok::<(), std::fmt::Error>(())
+/// ```
+pub fn romanize_into<W>(input: &str, dst: &mut W) -> fmt::Result
+where
+    W: fmt::Write + ?Sized,
+{
+    romanize_into_with(input, dst, &RevisedRomanization)
+}
+
+/// Romanizes text using the given transcriber, writing the result to `dst`.
+///
+/// Text that is not Hangeul is written as is, without allocating.
+pub fn romanize_into_with<W, T>(input: &str, dst: &mut W, transcriber: &T) -> fmt::Result
+where
+    W: fmt::Write + ?Sized,
+    T: Transcriber + ?Sized,
+{
+    for (is_word, run) in lexer::runs(input) {
+        if !is_word {
+            dst.write_str(run)?;
+            continue;
+        }
+
+        match Word::new(run) {
+            Ok(word) => dst.write_str(&word.romanize_with(transcriber))?,
+            Err(_) => dst.write_str(run)?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Text that is romanized using Revised Romanization rules when formatted
+///
+/// This romanizes lazily, directly into the formatter.
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::Romanized;
+///
+/// let title = "볼빨간사춘기";
+/// assert_eq!(format!("[{}]", Romanized(title)), "[bolppalgansachungi]");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Romanized<'a>(pub &'a str);
+
+impl fmt::Display for Romanized<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        romanize_into(self.0, f)
+    }
 }
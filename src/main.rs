@@ -1,8 +1,61 @@
-use hangeul;
+use std::{env, fs, path::Path, process};
 
-use std::env;
+use hangeul::{SubtitleFormat, Subtitles};
+
+const USAGE: &str = "\
+Usage: hangeul <input>
+       hangeul --subtitles [--pronounce] [--dual] <path>";
 
 fn main() {
-    let input = env::args().nth(1).expect("Usage: hangeul <input>");
-    println!("{}", hangeul::romanize(&input));
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.split_first() {
+        Some((flag, rest)) if flag == "--subtitles" => romanize_subtitles(rest),
+        Some((input, _)) => println!("{}", hangeul::romanize(input)),
+        None => exit(USAGE),
+    }
+}
+
+fn romanize_subtitles(args: &[String]) {
+    let mut pronounce = false;
+    let mut dual = false;
+    let mut path = None;
+
+    for arg in args {
+        match arg.as_str() {
+            "--pronounce" => pronounce = true,
+            "--dual" => dual = true,
+            _ if path.is_none() => path = Some(arg),
+            _ => exit(USAGE),
+        }
+    }
+
+    let path = match path {
+        Some(path) => Path::new(path),
+        None => exit(USAGE),
+    };
+
+    let input = match fs::read_to_string(path) {
+        Ok(s) => s,
+        Err(e) => exit(&format!("{}: {}", path.display(), e)),
+    };
+
+    let format = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .and_then(|ext| ext.parse().ok())
+        .or_else(|| SubtitleFormat::detect(&input));
+
+    let format = match format {
+        Some(format) => format,
+        None => exit(&format!("{}: unknown subtitle format", path.display())),
+    };
+
+    let subtitles = Subtitles::new(format).pronounce(pronounce).dual(dual);
+    print!("{}", subtitles.romanize(&input));
+}
+
+fn exit(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
 }
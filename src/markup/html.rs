@@ -0,0 +1,128 @@
+use super::{scan_entity, Writer};
+use crate::Transcriber;
+
+/// Elements whose contents are not text to be romanized.
+static RAW_ELEMENTS: [&str; 7] = ["code", "kbd", "pre", "samp", "script", "style", "textarea"];
+
+pub fn romanize<T>(input: &str, transcriber: &T) -> String
+where
+    T: Transcriber + ?Sized,
+{
+    let mut writer = Writer::new(transcriber);
+    let mut text_start = 0;
+    let mut i = 0;
+
+    while i < input.len() {
+        let rest = &input[i..];
+
+        let len = match rest.as_bytes()[0] {
+            b'<' => scan_markup(rest),
+            b'&' => scan_entity(rest),
+            _ => None,
+        };
+
+        match len {
+            Some(len) => {
+                writer.push_text(&input[text_start..i]);
+                writer.push_raw(&rest[..len]);
+                i += len;
+                text_start = i;
+            }
+            None => i += rest.chars().next().map(char::len_utf8).unwrap_or(1),
+        }
+    }
+
+    writer.push_text(&input[text_start..]);
+    writer.finish()
+}
+
+/// Returns the length of the tag, comment, or declaration at the start of
+/// `s`, or `None` if `s` does not start with markup.
+///
+/// The length of a start tag of a raw element (e.g., `code`, `script`)
+/// includes the element's contents and end tag.
+pub fn scan_markup(s: &str) -> Option<usize> {
+    if s.starts_with("<!--") {
+        return Some(find_end(s, 4, "-->"));
+    } else if s.starts_with("<![CDATA[") {
+        return Some(find_end(s, 9, "]]>"));
+    } else if s.starts_with("<!") || s.starts_with("<?") {
+        return Some(find_end(s, 2, ">"));
+    }
+
+    let is_end_tag = s.starts_with("</");
+    let name_start = if is_end_tag { 2 } else { 1 };
+
+    let name_len = s[name_start..]
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+        .unwrap_or(s.len() - name_start);
+
+    if name_len == 0 || !s.as_bytes()[name_start].is_ascii_alphabetic() {
+        return None;
+    }
+
+    let name = s[name_start..name_start + name_len].to_ascii_lowercase();
+    let len = scan_tag(s, name_start + name_len)?;
+
+    if !is_end_tag && !s[..len].ends_with("/>") && RAW_ELEMENTS.contains(&name.as_str()) {
+        let end_tag = format!("</{}", name);
+        let contents = s[len..].to_ascii_lowercase();
+
+        let end = match contents.find(&end_tag) {
+            Some(i) => {
+                let j = len + i;
+                scan_tag(s, j + end_tag.len()).map_or(s.len(), |k| k.max(j))
+            }
+            None => s.len(),
+        };
+
+        return Some(end);
+    }
+
+    Some(len)
+}
+
+/// Returns the length of a tag from `<` to `>`, starting after its name.
+fn scan_tag(s: &str, start: usize) -> Option<usize> {
+    let mut quote = None;
+
+    for (i, c) in s[start..].char_indices() {
+        match (quote, c) {
+            (None, '>') => return Some(start + i + 1),
+            (None, '<') => return None,
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (Some(q), _) if q == c => quote = None,
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Returns the index after the first `pat` at or after `start`, or the
+/// length of `s` if there is none.
+fn find_end(s: &str, start: usize, pat: &str) -> usize {
+    s[start..]
+        .find(pat)
+        .map_or(s.len(), |i| start + i + pat.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::scan_markup;
+
+    #[test]
+    fn test_scan_markup() {
+        assert_eq!(scan_markup("<b>굵게</b>"), Some(3));
+        assert_eq!(scan_markup("</b>"), Some(4));
+        assert_eq!(scan_markup(r#"<a title="a > b">"#), Some(17));
+        assert_eq!(scan_markup("<!-- 주석 -->"), Some(15));
+        assert_eq!(scan_markup("<!DOCTYPE html>"), Some(15));
+        assert_eq!(scan_markup("<br/>"), Some(5));
+        assert_eq!(scan_markup("<code>한국</code>!"), Some(19));
+        assert_eq!(scan_markup("<SCRIPT>a < b</Script >"), Some(23));
+        assert_eq!(scan_markup("< b"), None);
+        assert_eq!(scan_markup("<3"), None);
+        assert_eq!(scan_markup("<b"), None);
+    }
+}
@@ -0,0 +1,283 @@
+use super::{html, scan_entity, scan_url, Writer};
+use crate::Transcriber;
+
+pub fn romanize<T>(input: &str, transcriber: &T) -> String
+where
+    T: Transcriber + ?Sized,
+{
+    let labels = link_labels(input);
+
+    let mut writer = Writer::new(transcriber);
+    let mut lines = input.split_inclusive('\n').peekable();
+
+    // Lines of inline content, which are romanized together so that code
+    // spans and links can span lines.
+    let mut chunk = String::new();
+    let mut prev_blank = true;
+
+    while let Some(line) = lines.next() {
+        if let Some(fence) = fence(line) {
+            push_inline(&mut writer, &chunk, &labels);
+            chunk.clear();
+
+            writer.push_raw(line);
+
+            for line in lines.by_ref() {
+                writer.push_raw(line);
+
+                if is_closing_fence(line, fence) {
+                    break;
+                }
+            }
+
+            prev_blank = false;
+            continue;
+        }
+
+        let is_blank = line.trim().is_empty();
+        let is_code = prev_blank && is_indented_code(line);
+
+        if is_code || link_definition(line).is_some() {
+            push_inline(&mut writer, &chunk, &labels);
+            chunk.clear();
+            writer.push_raw(line);
+
+            // An indented code block continues until a line that is not
+            // indented.
+            while let Some(next) = lines
+                .peek()
+                .copied()
+                .filter(|next| is_code && (next.trim().is_empty() || is_indented_code(next)))
+            {
+                writer.push_raw(next);
+                lines.next();
+            }
+        } else {
+            chunk.push_str(line);
+        }
+
+        prev_blank = is_blank;
+    }
+
+    push_inline(&mut writer, &chunk, &labels);
+    writer.finish()
+}
+
+/// Romanizes the text of inline content.
+fn push_inline<T>(writer: &mut Writer<T>, s: &str, labels: &[String])
+where
+    T: Transcriber + ?Sized,
+{
+    let bytes = s.as_bytes();
+    let mut text_start = 0;
+    let mut i = 0;
+
+    while i < s.len() {
+        let rest = &s[i..];
+
+        let len = match bytes[i] {
+            b'\\' if bytes.get(i + 1).is_some_and(u8::is_ascii_punctuation) => Some(2),
+            b'`' => Some(scan_code_span(rest)),
+            b'<' => html::scan_markup(rest),
+            b'&' => scan_entity(rest),
+            b']' => match bytes.get(i + 1) {
+                // The destination and title of an inline link.
+                Some(b'(') => scan_destination(&rest[1..]).map(|n| n + 1),
+                // The label of a full or collapsed reference link.
+                Some(b'[') => rest[1..].find(']').map(|n| n + 2),
+                _ => None,
+            },
+            b'[' => scan_shortcut_reference(rest, labels),
+            _ if is_word_start(s, i) => scan_url(rest),
+            _ => None,
+        };
+
+        match len {
+            Some(len) => {
+                writer.push_text(&s[text_start..i]);
+                writer.push_raw(&rest[..len]);
+                i += len;
+                text_start = i;
+            }
+            None => i += rest.chars().next().map(char::len_utf8).unwrap_or(1),
+        }
+    }
+
+    writer.push_text(&s[text_start..]);
+}
+
+fn is_word_start(s: &str, i: usize) -> bool {
+    s[..i]
+        .chars()
+        .next_back()
+        .map_or(true, |c| c.is_whitespace() || c == '(')
+}
+
+/// Returns the length of the code span at the start of `s`.
+///
+/// If the backtick string is not closed, only it is returned, as it is
+/// literal text.
+fn scan_code_span(s: &str) -> usize {
+    let n = s.find(|c| c != '`').unwrap_or(s.len());
+    let mut i = n;
+
+    while let Some(j) = s[i..].find('`') {
+        let start = i + j;
+        let m = s[start..].find(|c| c != '`').unwrap_or(s.len() - start);
+
+        if m == n {
+            return start + m;
+        }
+
+        i = start + m;
+    }
+
+    n
+}
+
+/// Returns the length of a parenthesized link destination and title.
+fn scan_destination(s: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut quote = None;
+    let mut escaped = false;
+
+    for (i, c) in s.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+
+        match (quote, c) {
+            (_, '\\') => escaped = true,
+            (None, '"') | (None, '\'') if depth > 0 => quote = Some(c),
+            (Some(q), _) if q == c => quote = None,
+            (None, '(') => depth += 1,
+            (None, ')') => {
+                depth -= 1;
+
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            (None, '\n') if s[i + 1..].starts_with('\n') => return None,
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Returns the length of a shortcut or collapsed reference link whose label
+/// is defined in the document, since romanizing it would break the link.
+fn scan_shortcut_reference(s: &str, labels: &[String]) -> Option<usize> {
+    let end = s.find(']')?;
+    let label = normalize_label(&s[1..end]);
+
+    if !labels.contains(&label) || s[end + 1..].starts_with('(') {
+        return None;
+    }
+
+    if s[end + 1..].starts_with("[]") {
+        Some(end + 3)
+    } else {
+        Some(end + 1)
+    }
+}
+
+/// Returns the labels of all link reference definitions.
+fn link_labels(input: &str) -> Vec<String> {
+    input
+        .lines()
+        .filter_map(link_definition)
+        .map(normalize_label)
+        .collect()
+}
+
+/// Returns the label of a link reference definition, e.g., `[label]: /url`.
+fn link_definition(line: &str) -> Option<&str> {
+    let line = strip_indent(line, 3)?;
+    let rest = line.strip_prefix('[')?;
+    let end = rest.find("]:")?;
+    let label = &rest[..end];
+
+    if label.trim().is_empty() || label.contains('[') {
+        None
+    } else {
+        Some(label)
+    }
+}
+
+fn normalize_label(label: &str) -> String {
+    label
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Returns the fence character and length if the line opens a fenced code
+/// block.
+fn fence(line: &str) -> Option<(char, usize)> {
+    let line = strip_indent(line, 3)?;
+    let c = line.chars().next().filter(|&c| c == '`' || c == '~')?;
+    let n = line.find(|d| d != c).unwrap_or(line.len());
+
+    if n < 3 || (c == '`' && line[n..].contains('`')) {
+        None
+    } else {
+        Some((c, n))
+    }
+}
+
+fn is_closing_fence(line: &str, (c, n): (char, usize)) -> bool {
+    match strip_indent(line, 3) {
+        Some(line) => {
+            let m = line.find(|d| d != c).unwrap_or(line.len());
+            m >= n && line[m..].trim().is_empty()
+        }
+        None => false,
+    }
+}
+
+fn is_indented_code(line: &str) -> bool {
+    !line.trim().is_empty() && (line.starts_with("    ") || line.starts_with('\t'))
+}
+
+/// Removes up to `n` leading spaces, returning `None` if there are more.
+fn strip_indent(line: &str, n: usize) -> Option<&str> {
+    let indent = line.find(|c| c != ' ').unwrap_or(line.len());
+
+    if indent > n {
+        None
+    } else {
+        Some(&line[indent..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fence, scan_code_span, scan_destination};
+
+    #[test]
+    fn test_scan_code_span() {
+        assert_eq!(scan_code_span("`코드` 밖"), 8);
+        assert_eq!(scan_code_span("``a ` b`` c"), 9);
+        assert_eq!(scan_code_span("`열림"), 1);
+    }
+
+    #[test]
+    fn test_scan_destination() {
+        assert_eq!(scan_destination("(/위키) 뒤"), Some(9));
+        assert_eq!(scan_destination(r#"(/a "제목 (1)")"#), Some(17));
+        assert_eq!(scan_destination("(/a(b))"), Some(7));
+        assert_eq!(scan_destination("(열림"), None);
+    }
+
+    #[test]
+    fn test_fence() {
+        assert_eq!(fence("```rust\n"), Some(('`', 3)));
+        assert_eq!(fence("  ~~~~\n"), Some(('~', 4)));
+        assert_eq!(fence("``\n"), None);
+        assert_eq!(fence("    ```\n"), None);
+    }
+}
@@ -0,0 +1,270 @@
+mod html;
+mod markdown;
+
+use crate::{romanize_with, RevisedRomanization, Transcriber};
+
+/// Romanizes the text of an HTML document using Revised Romanization rules.
+///
+/// See [`romanize_html_with`].
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::romanize_html;
+///
+/// assert_eq!(
+///     romanize_html(r#"<p title="제목">안녕&nbsp;하세요</p>"#),
+///     r#"<p title="제목">annyeong&nbsp;haseyo</p>"#,
+/// );
+/// ```
+pub fn romanize_html(input: &str) -> String {
+    romanize_html_with(input, &RevisedRomanization)
+}
+
+/// Romanizes the text of an HTML document using the given transcriber.
+///
+/// Only text is romanized. Tags and their attributes, comments,
+/// declarations, and character references are left as is, as are the
+/// contents of elements that hold code or raw text (`code`, `kbd`, `pre`,
+/// `samp`, `script`, `style`, and `textarea`).
+///
+/// Text is romanized in runs between markup, so a word split by a tag is
+/// romanized as two words.
+pub fn romanize_html_with<T>(input: &str, transcriber: &T) -> String
+where
+    T: Transcriber + ?Sized,
+{
+    html::romanize(input, transcriber)
+}
+
+/// Romanizes the text of a Markdown document using Revised Romanization
+/// rules.
+///
+/// See [`romanize_markdown_with`].
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::romanize_markdown;
+///
+/// assert_eq!(
+///     romanize_markdown("# 한국어\n\n[위키](https://ko.wikipedia.org/wiki/한국어) `코드`\n"),
+///     "# hangugeo\n\n[wiki](https://ko.wikipedia.org/wiki/한국어) `코드`\n",
+/// );
+/// ```
+pub fn romanize_markdown(input: &str) -> String {
+    romanize_markdown_with(input, &RevisedRomanization)
+}
+
+/// Romanizes the text of a Markdown document using the given transcriber.
+///
+/// This supports the syntax of CommonMark. Only text is romanized, which
+/// includes the text of links and image descriptions. The following are
+/// left as is:
+///
+///   * fenced and indented code blocks, and code spans;
+///   * link destinations and titles, and reference link labels and
+///     definitions;
+///   * autolinks, raw HTML, character references, and backslash escapes;
+///   * bare URLs (e.g., `https://…` or `www.…`).
+///
+/// Markdown is not fully parsed. Notably, container blocks are not tracked,
+/// so code blocks in list items and block quotes are only recognized when
+/// they are fenced.
+pub fn romanize_markdown_with<T>(input: &str, transcriber: &T) -> String
+where
+    T: Transcriber + ?Sized,
+{
+    markdown::romanize(input, transcriber)
+}
+
+/// Writes text, romanizing all but raw markup.
+struct Writer<'a, T: ?Sized> {
+    transcriber: &'a T,
+    buf: String,
+}
+
+impl<'a, T> Writer<'a, T>
+where
+    T: Transcriber + ?Sized,
+{
+    fn new(transcriber: &'a T) -> Writer<'a, T> {
+        Writer {
+            transcriber,
+            buf: String::new(),
+        }
+    }
+
+    /// Romanizes text, leaving bare URLs as is.
+    fn push_text(&mut self, s: &str) {
+        let mut text_start = 0;
+        let mut i = 0;
+
+        while i < s.len() {
+            let is_word_start = s[..i].chars().next_back().map_or(true, |c| {
+                c.is_whitespace() || c == '(' || c == '>' || c == '"'
+            });
+
+            if is_word_start {
+                if let Some(len) = scan_url(&s[i..]) {
+                    self.buf
+                        .push_str(&romanize_with(&s[text_start..i], self.transcriber));
+                    self.buf.push_str(&s[i..i + len]);
+                    i += len;
+                    text_start = i;
+                    continue;
+                }
+            }
+
+            i += s[i..].chars().next().map_or(1, char::len_utf8);
+        }
+
+        self.buf
+            .push_str(&romanize_with(&s[text_start..], self.transcriber));
+    }
+
+    fn push_raw(&mut self, s: &str) {
+        self.buf.push_str(s);
+    }
+
+    fn finish(self) -> String {
+        self.buf
+    }
+}
+
+/// Returns the length of the character reference (e.g., `&amp;`, `&#xAC00;`)
+/// at the start of `s`.
+fn scan_entity(s: &str) -> Option<usize> {
+    let end = s.find(';')?;
+    let name = &s[1..end];
+
+    let is_valid = if let Some(hex) = name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+        !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit())
+    } else if let Some(dec) = name.strip_prefix('#') {
+        !dec.is_empty() && dec.chars().all(|c| c.is_ascii_digit())
+    } else {
+        !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric())
+    };
+
+    if is_valid {
+        Some(end + 1)
+    } else {
+        None
+    }
+}
+
+/// Returns the length of the URL at the start of `s`.
+///
+/// A URL ends at whitespace or a character that cannot be part of one
+/// unescaped.
+fn scan_url(s: &str) -> Option<usize> {
+    let is_url = ["http://", "https://", "ftp://", "mailto:", "www."]
+        .iter()
+        .any(|prefix| {
+            s.as_bytes()
+                .get(..prefix.len())
+                .is_some_and(|b| b.eq_ignore_ascii_case(prefix.as_bytes()))
+        });
+
+    if is_url {
+        Some(
+            s.find(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '"' | '`'))
+                .unwrap_or(s.len()),
+        )
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_romanize_html() {
+        assert_eq!(
+            romanize_html("<!DOCTYPE html>\n<html lang=\"ko\"><title>서울</title>"),
+            "<!DOCTYPE html>\n<html lang=\"ko\"><title>seoul</title>"
+        );
+        assert_eq!(
+            romanize_html(r#"<img alt="사진" src="/사진.png"> 사진"#),
+            r#"<img alt="사진" src="/사진.png"> sajin"#
+        );
+        assert_eq!(
+            romanize_html("<!-- 주석 --><b>굵게</b> &#xAC00;&amp; 가"),
+            "<!-- 주석 --><b>gukge</b> &#xAC00;&amp; ga"
+        );
+        assert_eq!(
+            romanize_html("<pre>코드</pre><script>let s = '한';</script>끝"),
+            "<pre>코드</pre><script>let s = '한';</script>kkeut"
+        );
+        assert_eq!(romanize_html("a < 가 & 나 > b"), "a < ga & na > b");
+        assert_eq!(
+            romanize_html("<p>https://ko.wikipedia.org/wiki/서울 서울</p>"),
+            "<p>https://ko.wikipedia.org/wiki/서울 seoul</p>"
+        );
+    }
+
+    #[test]
+    fn test_romanize_markdown() {
+        let input = "\
+# 제목
+
+본문 **강조** _기울임_ `코드` \\*별표\\*
+
+```
+코드 블록
+```
+
+    들여쓴 코드
+
+- [링크](/문서 \"제목\") ![그림](그림.png)
+- [참조][위키] [위키] <https://example.com/한국> <b>굵게</b>
+
+[위키]: https://ko.wikipedia.org/wiki/한국어 \"위키백과\"
+";
+
+        let expected = "\
+# jemok
+
+bonmun **gangjo** _giurim_ `코드` \\*byeolpyo\\*
+
+```
+코드 블록
+```
+
+    들여쓴 코드
+
+- [ringkeu](/문서 \"제목\") ![geurim](그림.png)
+- [chamjo][위키] [위키] <https://example.com/한국> <b>gukge</b>
+
+[위키]: https://ko.wikipedia.org/wiki/한국어 \"위키백과\"
+";
+
+        assert_eq!(romanize_markdown(input), expected);
+    }
+
+    #[test]
+    fn test_romanize_markdown_unclosed() {
+        assert_eq!(romanize_markdown("```\n코드"), "```\n코드");
+        assert_eq!(romanize_markdown("`코드"), "`kodeu");
+        assert_eq!(romanize_markdown("[링크](/문서"), "[ringkeu](/munseo");
+    }
+
+    #[test]
+    fn test_scan_entity() {
+        assert_eq!(scan_entity("&amp; "), Some(5));
+        assert_eq!(scan_entity("&#44032;"), Some(8));
+        assert_eq!(scan_entity("&#xac00;"), Some(8));
+        assert_eq!(scan_entity("& 가;"), None);
+        assert_eq!(scan_entity("&#;"), None);
+        assert_eq!(scan_entity("&amp"), None);
+    }
+
+    #[test]
+    fn test_scan_url() {
+        assert_eq!(scan_url("https://a.com/가 나"), Some(17));
+        assert_eq!(scan_url("WWW.a.com"), Some(9));
+        assert_eq!(scan_url("가"), None);
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{Choseong, Jongseong, Jungseong};
+
+pub fn transliterate_initial_consonant(
+    j: Choseong,
+    k: Jungseong,
+    voiced: bool,
+    apostrophes: bool,
+) -> &'static str {
+    match j {
+        Choseong::Giyeok if voiced => "g",
+        Choseong::Giyeok => "k",
+        Choseong::SsangGiyeok => "kk",
+        Choseong::Nieun => "n",
+        Choseong::Digeut if voiced => "d",
+        Choseong::Digeut => "t",
+        Choseong::SsangDigeut => "tt",
+        Choseong::Rieul => "r",
+        Choseong::Mieum => "m",
+        Choseong::Bieup if voiced => "b",
+        Choseong::Bieup => "p",
+        Choseong::SsangBieup => "pp",
+        Choseong::Siot if k == Jungseong::Wi => "sh",
+        Choseong::Siot => "s",
+        Choseong::SsangSiot => "ss",
+        Choseong::Ieung => "",
+        Choseong::Jieut if voiced => "j",
+        Choseong::Jieut => "ch",
+        Choseong::SsangJieut => "tch",
+        Choseong::Chieut if apostrophes => "ch'",
+        Choseong::Chieut => "ch",
+        Choseong::Kieuk if apostrophes => "k'",
+        Choseong::Kieuk => "k",
+        Choseong::Tieut if apostrophes => "t'",
+        Choseong::Tieut => "t",
+        Choseong::Pieup if apostrophes => "p'",
+        Choseong::Pieup => "p",
+        Choseong::Hieut => "h",
+    }
+}
+
+pub fn transliterate_medial_vowel(j: Jungseong, breves: bool) -> &'static str {
+    match j {
+        Jungseong::A => "a",
+        Jungseong::Ae => "ae",
+        Jungseong::Ya => "ya",
+        Jungseong::Yae => "yae",
+        Jungseong::Eo if breves => "ŏ",
+        Jungseong::Eo => "o",
+        Jungseong::E => "e",
+        Jungseong::Yeo if breves => "yŏ",
+        Jungseong::Yeo => "yo",
+        Jungseong::Ye => "ye",
+        Jungseong::O => "o",
+        Jungseong::Wa => "wa",
+        Jungseong::Wae => "wae",
+        Jungseong::Oe => "oe",
+        Jungseong::Yo => "yo",
+        Jungseong::U => "u",
+        Jungseong::Wo if breves => "wŏ",
+        Jungseong::Wo => "wo",
+        Jungseong::We => "we",
+        Jungseong::Wi => "wi",
+        Jungseong::Yu => "yu",
+        Jungseong::Eu if breves => "ŭ",
+        Jungseong::Eu => "u",
+        Jungseong::Ui if breves => "ŭi",
+        Jungseong::Ui => "ui",
+        Jungseong::I => "i",
+    }
+}
+
+pub fn transliterate_final_consonant(j: Jongseong) -> &'static str {
+    match j {
+        Jongseong::Giyeok
+        | Jongseong::SsangGiyeok
+        | Jongseong::GiyeokSiot
+        | Jongseong::RieulGiyeok
+        | Jongseong::Kieuk => "k",
+        Jongseong::Nieun | Jongseong::NieunJieut | Jongseong::NieunHieut => "n",
+        Jongseong::Digeut
+        | Jongseong::Siot
+        | Jongseong::SsangSiot
+        | Jongseong::Jieut
+        | Jongseong::Chieut
+        | Jongseong::Tieut
+        | Jongseong::Hieut => "t",
+        Jongseong::Rieul
+        | Jongseong::RieulBieup
+        | Jongseong::RieulSiot
+        | Jongseong::RieulTieut
+        | Jongseong::RieulHieut => "l",
+        Jongseong::Mieum | Jongseong::RieulMieum => "m",
+        Jongseong::Bieup | Jongseong::RieulPieup | Jongseong::BieupSiot | Jongseong::Pieup => "p",
+        Jongseong::Ieung => "ng",
+    }
+}
@@ -0,0 +1,174 @@
+mod jamo;
+
+use self::jamo::*;
+use crate::{Choseong, Context, Jongseong, Syllable, Transcriber};
+
+/// McCune–Reischauer romanization
+///
+/// Like Revised Romanization, McCune–Reischauer transcribes the standard
+/// pronunciation of a word. Aspirated consonants are marked with an
+/// apostrophe (p'a), ㅓ and ㅡ are written with breves (ŏ, ŭ), and plain
+/// stops and affricates are voiced (g, d, b, j) between voiced sounds but
+/// voiceless (k, t, p, ch) word-initially or after a voiceless final.
+///
+/// The ASCII variant drops the breves, and optionally the apostrophes, for
+/// contexts that cannot represent them.
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::McCuneReischauer;
+///
+/// let mr = McCuneReischauer::new();
+/// assert_eq!(mr.romanize("평양"), "p'yŏngyang");
+/// assert_eq!(mr.romanize("한국어"), "han'gugŏ");
+///
+/// let mr = McCuneReischauer::ascii(false);
+/// assert_eq!(mr.romanize("평양"), "pyongyang");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct McCuneReischauer {
+    breves: bool,
+    apostrophes: bool,
+}
+
+impl McCuneReischauer {
+    /// Creates the standard variant, with breves and apostrophes.
+    pub fn new() -> McCuneReischauer {
+        McCuneReischauer {
+            breves: true,
+            apostrophes: true,
+        }
+    }
+
+    /// Creates the ASCII variant, which writes ŏ and ŭ as o and u.
+    ///
+    /// `apostrophes` controls whether aspiration and the n'g boundary are
+    /// still marked.
+    pub fn ascii(apostrophes: bool) -> McCuneReischauer {
+        McCuneReischauer {
+            breves: false,
+            apostrophes,
+        }
+    }
+
+    /// Romanizes text using McCune–Reischauer rules.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hangeul::McCuneReischauer;
+    ///
+    /// let mr = McCuneReischauer::new();
+    /// assert_eq!(mr.romanize("부산"), "pusan");
+    /// assert_eq!(mr.romanize("김치"), "kimch'i");
+    /// assert_eq!(mr.romanize("TWICE - 시그널"), "TWICE - sigŭnŏl");
+    /// ```
+    pub fn romanize(&self, input: &str) -> String {
+        crate::romanize_with(input, self)
+    }
+}
+
+impl Transcriber for McCuneReischauer {
+    fn transcribe(&self, syllables: &[Syllable], _: &Context) -> String {
+        let mut res = String::new();
+
+        for (i, s) in syllables.iter().enumerate() {
+            let (j, k, m) = s.decompose();
+
+            // `None` at the start of a word; otherwise, the final consonant
+            // of the previous syllable, if any.
+            let prev = i.checked_sub(1).map(|h| syllables[h].jongseong());
+
+            match prev {
+                Some(Some(Jongseong::Rieul)) if j == Choseong::Rieul => res.push('l'),
+                Some(Some(Jongseong::Nieun)) if j == Choseong::Giyeok && self.apostrophes => {
+                    res.push_str("'g")
+                }
+                _ => {
+                    let voiced = is_voiced(prev, j);
+                    res.push_str(transliterate_initial_consonant(
+                        j,
+                        k,
+                        voiced,
+                        self.apostrophes,
+                    ));
+                }
+            }
+
+            res.push_str(transliterate_medial_vowel(k, self.breves));
+
+            if let Some(m) = m {
+                res.push_str(transliterate_final_consonant(m));
+            }
+        }
+
+        res
+    }
+}
+
+impl Default for McCuneReischauer {
+    fn default() -> McCuneReischauer {
+        McCuneReischauer::new()
+    }
+}
+
+/// Returns whether an initial consonant `j` is in a voiced environment.
+///
+/// Plain consonants are voiced after a vowel or a voiced final (ㄴ, ㅁ, ㅇ,
+/// ㄹ). After ㄹ, ㄷ and ㅈ are conventionally left voiceless (lt, lch).
+pub(crate) fn is_voiced(prev: Option<Option<Jongseong>>, j: Choseong) -> bool {
+    match prev {
+        None => false,
+        Some(None) => true,
+        Some(Some(Jongseong::Rieul)) => j != Choseong::Digeut && j != Choseong::Jieut,
+        Some(Some(m)) => matches!(m, Jongseong::Nieun | Jongseong::Mieum | Jongseong::Ieung),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::McCuneReischauer;
+
+    fn r(s: &str) -> String {
+        McCuneReischauer::new().romanize(s)
+    }
+
+    #[test]
+    fn test_aspiration() {
+        assert_eq!(r("평양"), "p'yŏngyang");
+        assert_eq!(r("청주"), "ch'ŏngju");
+        assert_eq!(r("김치"), "kimch'i");
+        assert_eq!(r("좋다"), "chot'a");
+    }
+
+    #[test]
+    fn test_voicing() {
+        assert_eq!(r("부산"), "pusan");
+        assert_eq!(r("대구"), "taegu");
+        assert_eq!(r("국밥"), "kukpap");
+        assert_eq!(r("물고기"), "mulgogi");
+        assert_eq!(r("발달"), "paltal");
+        assert_eq!(r("김세정"), "kimsejŏng");
+    }
+
+    #[test]
+    fn test_boundaries() {
+        assert_eq!(r("한국어"), "han'gugŏ");
+        assert_eq!(r("독립"), "tongnip");
+        assert_eq!(r("신라"), "silla");
+        assert_eq!(r("쉬다"), "shwida");
+    }
+
+    #[test]
+    fn test_ascii() {
+        let mr = McCuneReischauer::ascii(true);
+        assert_eq!(mr.romanize("서울"), "soul");
+        assert_eq!(mr.romanize("평양"), "p'yongyang");
+        assert_eq!(mr.romanize("한국어"), "han'gugo");
+
+        let mr = McCuneReischauer::ascii(false);
+        assert_eq!(mr.romanize("평양"), "pyongyang");
+        assert_eq!(mr.romanize("한국어"), "hangugo");
+    }
+}
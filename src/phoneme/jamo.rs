@@ -0,0 +1,138 @@
+use super::Phoneme::{self, *};
+use crate::{Choseong, Jongseong, Jungseong};
+
+pub fn initial_consonant(j: Choseong) -> Option<Phoneme> {
+    match j {
+        Choseong::Giyeok => Some(K),
+        Choseong::SsangGiyeok => Some(Kk),
+        Choseong::Nieun => Some(N),
+        Choseong::Digeut => Some(T),
+        Choseong::SsangDigeut => Some(Tt),
+        Choseong::Rieul => Some(L),
+        Choseong::Mieum => Some(M),
+        Choseong::Bieup => Some(P),
+        Choseong::SsangBieup => Some(Pp),
+        Choseong::Siot => Some(S),
+        Choseong::SsangSiot => Some(Ss),
+        Choseong::Ieung => None,
+        Choseong::Jieut => Some(J),
+        Choseong::SsangJieut => Some(Jj),
+        Choseong::Chieut => Some(Ch),
+        Choseong::Kieuk => Some(Kh),
+        Choseong::Tieut => Some(Th),
+        Choseong::Pieup => Some(Ph),
+        Choseong::Hieut => Some(H),
+    }
+}
+
+pub fn medial_vowel(k: Jungseong) -> Phoneme {
+    match k {
+        Jungseong::A => A,
+        Jungseong::Ae => Ae,
+        Jungseong::Ya => Ya,
+        Jungseong::Yae => Yae,
+        Jungseong::Eo => Eo,
+        Jungseong::E => E,
+        Jungseong::Yeo => Yeo,
+        Jungseong::Ye => Ye,
+        Jungseong::O => O,
+        Jungseong::Wa => Wa,
+        Jungseong::Wae => Wae,
+        Jungseong::Oe => Oe,
+        Jungseong::Yo => Yo,
+        Jungseong::U => U,
+        Jungseong::Wo => Wo,
+        Jungseong::We => We,
+        Jungseong::Wi => Wi,
+        Jungseong::Yu => Yu,
+        Jungseong::Eu => Eu,
+        Jungseong::Ui => Ui,
+        Jungseong::I => I,
+    }
+}
+
+pub fn final_consonant(m: Jongseong) -> Phoneme {
+    match m {
+        Jongseong::Giyeok
+        | Jongseong::SsangGiyeok
+        | Jongseong::GiyeokSiot
+        | Jongseong::RieulGiyeok
+        | Jongseong::Kieuk => K,
+        Jongseong::Nieun | Jongseong::NieunJieut | Jongseong::NieunHieut => N,
+        Jongseong::Digeut
+        | Jongseong::Siot
+        | Jongseong::SsangSiot
+        | Jongseong::Jieut
+        | Jongseong::Chieut
+        | Jongseong::Tieut
+        | Jongseong::Hieut => T,
+        Jongseong::Rieul
+        | Jongseong::RieulBieup
+        | Jongseong::RieulSiot
+        | Jongseong::RieulTieut
+        | Jongseong::RieulHieut => L,
+        Jongseong::Mieum | Jongseong::RieulMieum => M,
+        Jongseong::Bieup | Jongseong::RieulPieup | Jongseong::BieupSiot | Jongseong::Pieup => P,
+        Jongseong::Ieung => Ng,
+    }
+}
+
+/// Returns the X-SAMPA phone of a phoneme.
+///
+/// Tense consonants, which have no X-SAMPA diacritic, are written with an
+/// asterisk, as is common in Korean lexicons.
+pub fn x_sampa(phoneme: Phoneme) -> &'static str {
+    match phoneme {
+        K => "k",
+        Kk => "k*",
+        Kh => "k_h",
+        T => "t",
+        Tt => "t*",
+        Th => "t_h",
+        P => "p",
+        Pp => "p*",
+        Ph => "p_h",
+        J => "ts\\",
+        Jj => "ts\\*",
+        Ch => "ts\\_h",
+        S => "s",
+        Ss => "s*",
+        H => "h",
+        M => "m",
+        N => "n",
+        Ng => "N",
+        L => "l",
+        A => "a",
+        Ae => "E",
+        Ya => "ja",
+        Yae => "jE",
+        Eo => "V",
+        E => "e",
+        Yeo => "jV",
+        Ye => "je",
+        O => "o",
+        Wa => "wa",
+        Wae => "wE",
+        Oe => "2",
+        Yo => "jo",
+        U => "u",
+        Wo => "wV",
+        We => "we",
+        Wi => "Hi",
+        Yu => "ju",
+        Eu => "M",
+        Ui => "M\\i",
+        I => "i",
+    }
+}
+
+/// Returns the X-SAMPA phone of a phoneme in a coda, or `None` if it is the
+/// same as in an onset.
+pub fn x_sampa_coda(phoneme: Phoneme) -> Option<&'static str> {
+    match phoneme {
+        K => Some("k_}"),
+        T => Some("t_}"),
+        P => Some("p_}"),
+        _ => None,
+    }
+}
@@ -0,0 +1,397 @@
+mod jamo;
+
+use std::collections::HashMap;
+
+use self::jamo::*;
+use crate::{Context, Pronouncer, Syllable, Transcriber};
+
+/// A phoneme of standard Korean
+///
+/// Consonants are named after their Revised Romanization (ㅈ is `J`), with
+/// a doubled letter for tense and an h for aspirated consonants. Vowels,
+/// including those with an on-glide, are single phonemes, since lexicons
+/// disagree on how to split them. A phone set may still map them to
+/// multiple phones.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Phoneme {
+    K,
+    Kk,
+    Kh,
+    T,
+    Tt,
+    Th,
+    P,
+    Pp,
+    Ph,
+    J,
+    Jj,
+    Ch,
+    S,
+    Ss,
+    H,
+    M,
+    N,
+    Ng,
+    L,
+    A,
+    Ae,
+    Ya,
+    Yae,
+    Eo,
+    E,
+    Yeo,
+    Ye,
+    O,
+    Wa,
+    Wae,
+    Oe,
+    Yo,
+    U,
+    Wo,
+    We,
+    Wi,
+    Yu,
+    Eu,
+    Ui,
+    I,
+}
+
+impl Phoneme {
+    /// All phonemes, consonants first, in jamo order.
+    pub const ALL: [Phoneme; 40] = [
+        Phoneme::K,
+        Phoneme::Kk,
+        Phoneme::Kh,
+        Phoneme::T,
+        Phoneme::Tt,
+        Phoneme::Th,
+        Phoneme::P,
+        Phoneme::Pp,
+        Phoneme::Ph,
+        Phoneme::J,
+        Phoneme::Jj,
+        Phoneme::Ch,
+        Phoneme::S,
+        Phoneme::Ss,
+        Phoneme::H,
+        Phoneme::M,
+        Phoneme::N,
+        Phoneme::Ng,
+        Phoneme::L,
+        Phoneme::A,
+        Phoneme::Ae,
+        Phoneme::Ya,
+        Phoneme::Yae,
+        Phoneme::Eo,
+        Phoneme::E,
+        Phoneme::Yeo,
+        Phoneme::Ye,
+        Phoneme::O,
+        Phoneme::Wa,
+        Phoneme::Wae,
+        Phoneme::Oe,
+        Phoneme::Yo,
+        Phoneme::U,
+        Phoneme::Wo,
+        Phoneme::We,
+        Phoneme::Wi,
+        Phoneme::Yu,
+        Phoneme::Eu,
+        Phoneme::Ui,
+        Phoneme::I,
+    ];
+
+    /// Returns whether the phoneme is a vowel.
+    pub fn is_vowel(self) -> bool {
+        self as usize >= Phoneme::A as usize
+    }
+}
+
+/// The phonemes of a single pronounced syllable
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::{Phoneme, PhonemicSyllable, Syllable};
+///
+/// let s = PhonemicSyllable::from(Syllable::from_char('강').unwrap());
+/// assert_eq!(s.onset(), Some(Phoneme::K));
+/// assert_eq!(s.nucleus(), Phoneme::A);
+/// assert_eq!(s.coda(), Some(Phoneme::Ng));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PhonemicSyllable {
+    onset: Option<Phoneme>,
+    nucleus: Phoneme,
+    coda: Option<Phoneme>,
+}
+
+impl PhonemicSyllable {
+    pub fn new(
+        onset: Option<Phoneme>,
+        nucleus: Phoneme,
+        coda: Option<Phoneme>,
+    ) -> PhonemicSyllable {
+        PhonemicSyllable {
+            onset,
+            nucleus,
+            coda,
+        }
+    }
+
+    pub fn onset(&self) -> Option<Phoneme> {
+        self.onset
+    }
+
+    pub fn nucleus(&self) -> Phoneme {
+        self.nucleus
+    }
+
+    pub fn coda(&self) -> Option<Phoneme> {
+        self.coda
+    }
+
+    /// Returns the phonemes of the syllable in order.
+    pub fn phonemes(&self) -> impl Iterator<Item = Phoneme> {
+        self.onset
+            .into_iter()
+            .chain(Some(self.nucleus))
+            .chain(self.coda)
+    }
+}
+
+impl From<Syllable> for PhonemicSyllable {
+    /// Converts a syllable to phonemes as it is spelled.
+    ///
+    /// The syllable is expected to already be pronounced, e.g., by
+    /// [`Pronouncer`]. Final consonants are neutralized to one of the seven
+    /// coda phonemes.
+    fn from(s: Syllable) -> PhonemicSyllable {
+        let (j, k, m) = s.decompose();
+
+        PhonemicSyllable {
+            onset: initial_consonant(j),
+            nucleus: medial_vowel(k),
+            coda: m.map(final_consonant),
+        }
+    }
+}
+
+/// A mapping from phonemes to phones of a speech synthesis lexicon
+///
+/// Phones are joined by a separator, and syllables by a boundary marker.
+/// The default phone set is X-SAMPA, with spaces between phones and periods
+/// between syllables. Any phone can be overridden, separately for onsets
+/// and codas.
+///
+/// A phone set is also a [`Transcriber`] using the same pronunciation as
+/// [`Ipa`](crate::Ipa), so it can transcribe arbitrary text.
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::{romanize_with, Phoneme, PhoneSet};
+///
+/// let x_sampa = PhoneSet::x_sampa();
+/// assert_eq!(romanize_with("국물", &x_sampa), "k u N . m u l");
+/// assert_eq!(romanize_with("밥", &x_sampa), "p a p_}");
+///
+/// let custom = PhoneSet::x_sampa()
+///     .phone(Phoneme::Eo, "eo")
+///     .coda(Phoneme::P, "p")
+///     .separator("")
+///     .boundary("-");
+/// assert_eq!(romanize_with("법", &custom), "peop");
+/// assert_eq!(romanize_with("어머니", &custom), "eo-meo-ni");
+/// ```
+#[derive(Clone, Debug)]
+pub struct PhoneSet {
+    phones: HashMap<Phoneme, String>,
+    codas: HashMap<Phoneme, String>,
+    separator: String,
+    boundary: String,
+}
+
+impl PhoneSet {
+    /// Creates an X-SAMPA phone set.
+    ///
+    /// Tense consonants, which have no X-SAMPA diacritic, are written with
+    /// an asterisk (k*), and final stops are unreleased (k_}).
+    pub fn x_sampa() -> PhoneSet {
+        let phones = Phoneme::ALL
+            .iter()
+            .map(|&p| (p, x_sampa(p).to_string()))
+            .collect();
+
+        let codas = Phoneme::ALL
+            .iter()
+            .filter_map(|&p| x_sampa_coda(p).map(|s| (p, s.to_string())))
+            .collect();
+
+        PhoneSet {
+            phones,
+            codas,
+            separator: String::from(" "),
+            boundary: String::from("."),
+        }
+    }
+
+    /// Sets the phone of a phoneme.
+    ///
+    /// This is used in codas as well, unless overridden by
+    /// [`PhoneSet::coda`].
+    pub fn phone<S>(mut self, phoneme: Phoneme, phone: S) -> PhoneSet
+    where
+        S: Into<String>,
+    {
+        self.phones.insert(phoneme, phone.into());
+        self.codas.remove(&phoneme);
+        self
+    }
+
+    /// Sets the phone of a phoneme in a coda.
+    pub fn coda<S>(mut self, phoneme: Phoneme, phone: S) -> PhoneSet
+    where
+        S: Into<String>,
+    {
+        self.codas.insert(phoneme, phone.into());
+        self
+    }
+
+    /// Sets the string between phones of a syllable.
+    pub fn separator<S>(mut self, separator: S) -> PhoneSet
+    where
+        S: Into<String>,
+    {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Sets the string between syllables.
+    ///
+    /// The separator is written on both sides of the boundary.
+    pub fn boundary<S>(mut self, boundary: S) -> PhoneSet
+    where
+        S: Into<String>,
+    {
+        self.boundary = boundary.into();
+        self
+    }
+
+    /// Writes a sequence of syllables using the phone set.
+    pub fn render(&self, syllables: &[PhonemicSyllable]) -> String {
+        let mut res = String::new();
+
+        for (i, s) in syllables.iter().enumerate() {
+            if i > 0 {
+                res.push_str(&self.separator);
+                res.push_str(&self.boundary);
+                res.push_str(&self.separator);
+            }
+
+            let phones = s
+                .onset()
+                .map(|p| self.phone_of(p))
+                .into_iter()
+                .chain(Some(self.phone_of(s.nucleus())))
+                .chain(s.coda().map(|p| match self.codas.get(&p) {
+                    Some(phone) => phone,
+                    None => self.phone_of(p),
+                }));
+
+            for (j, phone) in phones.enumerate() {
+                if j > 0 {
+                    res.push_str(&self.separator);
+                }
+
+                res.push_str(phone);
+            }
+        }
+
+        res
+    }
+
+    fn phone_of(&self, phoneme: Phoneme) -> &str {
+        // Every phoneme is given a phone on creation.
+        self.phones.get(&phoneme).map_or("", String::as_str)
+    }
+}
+
+impl Default for PhoneSet {
+    fn default() -> PhoneSet {
+        PhoneSet::x_sampa()
+    }
+}
+
+impl Transcriber for PhoneSet {
+    fn pronouncer(&self) -> Option<Pronouncer> {
+        Some(Pronouncer::phonetic())
+    }
+
+    fn transcribe(&self, syllables: &[Syllable], _: &Context) -> String {
+        let phonemes: Vec<_> = syllables
+            .iter()
+            .map(|&s| PhonemicSyllable::from(s))
+            .collect();
+        self.render(&phonemes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PhoneSet, Phoneme, PhonemicSyllable};
+    use crate::{romanize_with, Word};
+
+    #[test]
+    fn test_phonemes() {
+        let syllables = Word::new("국밥").unwrap().phonemes();
+
+        assert_eq!(
+            syllables,
+            vec![
+                PhonemicSyllable::new(Some(Phoneme::K), Phoneme::U, Some(Phoneme::K)),
+                PhonemicSyllable::new(Some(Phoneme::Pp), Phoneme::A, Some(Phoneme::P)),
+            ]
+        );
+
+        let phonemes: Vec<_> = syllables.iter().flat_map(|s| s.phonemes()).collect();
+        assert_eq!(
+            phonemes,
+            [
+                Phoneme::K,
+                Phoneme::U,
+                Phoneme::K,
+                Phoneme::Pp,
+                Phoneme::A,
+                Phoneme::P
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_vowel() {
+        assert!(!Phoneme::L.is_vowel());
+        assert!(Phoneme::A.is_vowel());
+        assert!(Phoneme::I.is_vowel());
+    }
+
+    #[test]
+    fn test_x_sampa() {
+        let x_sampa = PhoneSet::x_sampa();
+        assert_eq!(romanize_with("국밥", &x_sampa), "k u k_} . p* a p_}");
+        assert_eq!(romanize_with("참", &x_sampa), "ts\\_h a m");
+        assert_eq!(romanize_with("의사", &x_sampa), "M\\i . s a");
+        assert_eq!(romanize_with("신라면", &x_sampa), "s i l . l a . m jV n");
+        assert_eq!(romanize_with("한국어", &x_sampa), "h a n . k u . k V");
+    }
+
+    #[test]
+    fn test_custom() {
+        let phone_set = PhoneSet::x_sampa()
+            .phone(Phoneme::Yeo, "j V")
+            .phone(Phoneme::K, "g")
+            .boundary("|");
+        assert_eq!(romanize_with("면", &phone_set), "m j V n");
+        assert_eq!(romanize_with("국", &phone_set), "g u g");
+    }
+}
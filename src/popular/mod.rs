@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+
+use crate::revised_romanization::jamo::*;
+use crate::{Choseong, Context, Jongseong, Jungseong, Syllable, Transcriber};
+
+/// Common spellings of single-syllable words, mostly surnames.
+static EXCEPTIONS: [(&str, &str); 16] = [
+    ("이", "lee"),
+    ("오", "oh"),
+    ("우", "woo"),
+    ("유", "yoo"),
+    ("김", "kim"),
+    ("박", "park"),
+    ("최", "choi"),
+    ("정", "jung"),
+    ("조", "cho"),
+    ("강", "kang"),
+    ("신", "shin"),
+    ("임", "lim"),
+    ("노", "noh"),
+    ("구", "koo"),
+    ("문", "moon"),
+    ("배", "bae"),
+];
+
+/// A popular, de facto romanization
+///
+/// Fans, artists, and lyric contributors often use spellings that are closer
+/// to English orthography than Revised Romanization, e.g., 우주 => "woojoo"
+/// or 너 => "nuh". This profile transcribes the standard pronunciation like
+/// Revised Romanization, with the following changes, each of which can be
+/// disabled:
+///
+///   * `oo`: ㅜ is written oo (우주 => "woojoo").
+///   * `ee`: ㅣ is written ee at the end of a word (지 => "jee").
+///   * `u_for_eo`: ㅓ is written u, or uh at the end of a word (정 => "jung",
+///     너 => "nuh").
+///   * `exceptions`: words with established spellings (이 => "lee",
+///     오 => "oh"). More can be added with [`Popular::exception`].
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::Popular;
+///
+/// let popular = Popular::new();
+/// assert_eq!(popular.romanize("사랑해"), "saranghae");
+/// assert_eq!(popular.romanize("우주"), "woojoo");
+/// assert_eq!(popular.romanize("너"), "nuh");
+/// assert_eq!(popular.romanize("이"), "lee");
+///
+/// let popular = Popular::new().oo(false);
+/// assert_eq!(popular.romanize("우주"), "uju");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Popular {
+    oo: bool,
+    ee: bool,
+    u_for_eo: bool,
+    builtin_exceptions: bool,
+    exceptions: HashMap<String, String>,
+}
+
+impl Popular {
+    /// Creates a profile with all changes and the built-in exceptions
+    /// enabled.
+    pub fn new() -> Popular {
+        Popular {
+            oo: true,
+            ee: true,
+            u_for_eo: true,
+            builtin_exceptions: true,
+            exceptions: HashMap::new(),
+        }
+    }
+
+    /// Sets whether ㅜ is written oo.
+    pub fn oo(mut self, oo: bool) -> Popular {
+        self.oo = oo;
+        self
+    }
+
+    /// Sets whether ㅣ is written ee at the end of a word.
+    pub fn ee(mut self, ee: bool) -> Popular {
+        self.ee = ee;
+        self
+    }
+
+    /// Sets whether ㅓ is written u, or uh at the end of a word.
+    pub fn u_for_eo(mut self, u_for_eo: bool) -> Popular {
+        self.u_for_eo = u_for_eo;
+        self
+    }
+
+    /// Sets whether the built-in exceptions are used.
+    ///
+    /// This does not affect exceptions added with [`Popular::exception`],
+    /// which also take precedence over the built-in ones.
+    pub fn exceptions(mut self, exceptions: bool) -> Popular {
+        self.builtin_exceptions = exceptions;
+        self
+    }
+
+    /// Adds a word with a fixed spelling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hangeul::Popular;
+    ///
+    /// let popular = Popular::new().exception("소녀", "sonyuh");
+    /// assert_eq!(popular.romanize("우주 소녀"), "woojoo sonyuh");
+    /// ```
+    pub fn exception<S, T>(mut self, word: S, spelling: T) -> Popular
+    where
+        S: Into<String>,
+        T: Into<String>,
+    {
+        self.exceptions.insert(word.into(), spelling.into());
+        self
+    }
+
+    /// Returns the fixed spelling of a word, if any.
+    fn spelling_of(&self, word: &str) -> Option<&str> {
+        if let Some(spelling) = self.exceptions.get(word) {
+            return Some(spelling);
+        }
+
+        if self.builtin_exceptions {
+            EXCEPTIONS
+                .iter()
+                .find(|&&(k, _)| k == word)
+                .map(|&(_, v)| v)
+        } else {
+            None
+        }
+    }
+
+    /// Romanizes text using the popular profile.
+    pub fn romanize(&self, input: &str) -> String {
+        crate::romanize_with(input, self)
+    }
+
+    fn transliterate_medial_vowel(&self, k: Jungseong, open: bool, last: bool) -> &'static str {
+        match k {
+            Jungseong::U if self.oo => "oo",
+            Jungseong::I if self.ee && open && last => "ee",
+            Jungseong::Eo if self.u_for_eo && open && last => "uh",
+            Jungseong::Eo if self.u_for_eo => "u",
+            _ => transliterate_medial_vowel(k),
+        }
+    }
+}
+
+impl Default for Popular {
+    fn default() -> Popular {
+        Popular::new()
+    }
+}
+
+impl Transcriber for Popular {
+    fn transcribe(&self, syllables: &[Syllable], ctx: &Context) -> String {
+        if self.builtin_exceptions || !self.exceptions.is_empty() {
+            let word: String = ctx.spelling().iter().map(|s| s.as_char()).collect();
+
+            if let Some(spelling) = self.spelling_of(&word) {
+                return spelling.to_string();
+            }
+        }
+
+        let mut it = syllables.iter().peekable();
+        let mut res = String::new();
+
+        let mut skip = false;
+
+        while let Some(s) = it.next() {
+            let (j, k, m) = s.decompose();
+            let last = it.peek().is_none();
+
+            if !skip {
+                if j == Choseong::Ieung && k == Jungseong::U && self.oo {
+                    res.push('w');
+                } else {
+                    res.push_str(transliterate_initial_consonant(j));
+                }
+            } else {
+                skip = false;
+            }
+
+            res.push_str(self.transliterate_medial_vowel(k, m.is_none(), last));
+
+            if let Some(m) = m {
+                let t = it.peek();
+
+                if let Some(n) = t.map(|t| t.choseong()) {
+                    if m == Jongseong::Rieul && n == Choseong::Rieul {
+                        res.push_str("ll");
+                        skip = true;
+                        continue;
+                    }
+                }
+
+                res.push_str(transliterate_final_consonant(m));
+            }
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Popular;
+
+    fn r(s: &str) -> String {
+        Popular::new().romanize(s)
+    }
+
+    #[test]
+    fn test_romanize() {
+        assert_eq!(r("사랑해"), "saranghae");
+        assert_eq!(r("우주소녀"), "woojoosonyeo");
+        assert_eq!(r("러블리즈"), "rubeullijeu");
+        assert_eq!(r("지"), "jee");
+        assert_eq!(r("지민"), "jimin");
+        assert_eq!(r("너"), "nuh");
+        assert_eq!(r("정국"), "junggook");
+    }
+
+    #[test]
+    fn test_exceptions() {
+        assert_eq!(r("이"), "lee");
+        assert_eq!(r("오"), "oh");
+        assert_eq!(r("김 세정"), "kim sejung");
+
+        let popular = Popular::new().exceptions(false);
+        assert_eq!(popular.romanize("이"), "ee");
+        assert_eq!(popular.romanize("오"), "o");
+    }
+
+    #[test]
+    fn test_custom_exceptions() {
+        let popular = Popular::new()
+            .exception("이", "yi")
+            .exception("소녀", "sonyuh")
+            .exceptions(true);
+        assert_eq!(popular.romanize("이"), "yi");
+        assert_eq!(popular.romanize("소녀"), "sonyuh");
+        assert_eq!(popular.romanize("오"), "oh");
+
+        let popular = Popular::new().exception("소녀", "sonyuh").exceptions(false);
+        assert_eq!(popular.romanize("소녀"), "sonyuh");
+        assert_eq!(popular.romanize("이"), "ee");
+
+        let popular = Popular::new()
+            .exceptions(false)
+            .exception("소녀", "sonyuh")
+            .exceptions(true);
+        assert_eq!(popular.romanize("소녀"), "sonyuh");
+        assert_eq!(popular.romanize("이"), "lee");
+    }
+
+    #[test]
+    fn test_options() {
+        let popular = Popular::new()
+            .oo(false)
+            .ee(false)
+            .u_for_eo(false)
+            .exceptions(false);
+
+        for s in &["사랑해", "우주소녀", "러블리즈", "이", "너", "정국"] {
+            assert_eq!(popular.romanize(s), crate::romanize(s));
+        }
+    }
+}
@@ -1,95 +1,152 @@
 mod rules;
+mod table;
 
 use self::rules::*;
-use crate::Word;
-
+use self::table::Table;
+use crate::{Choseong, Word};
+
+/// Applies the rules of standard pronunciation to words.
+///
+/// When `strict`, tensification and vowel simplification are also reflected
+/// in the result. These are not represented in romanization.
+///
+/// Tensification after the final ㄴ, ㅁ, ㄼ, or ㄾ of a verb stem (rules 24
+/// and 25) cannot be told apart from spelling alone, e.g., 안다 (to hug) is
+/// pronounced 안따, but 한국어 is not pronounced 한꾸거. It is applied to
+/// every word unless disabled with [`Pronouncer::stem_tensing`].
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::{Pronouncer, Word};
+///
+/// let word = Word::new("국밥").unwrap();
+/// assert_eq!(Pronouncer::new(false).pronounce(&word).to_string(), "국밥");
+/// assert_eq!(Pronouncer::new(true).pronounce(&word).to_string(), "국빱");
+/// ```
+#[derive(Clone, Copy, Debug)]
 pub struct Pronouncer {
     strict: bool,
+    stem_tensing: bool,
 }
 
 impl Pronouncer {
     pub fn new(strict: bool) -> Pronouncer {
-        Pronouncer { strict }
+        Pronouncer {
+            strict,
+            stem_tensing: true,
+        }
     }
 
-    pub fn pronounce(&self, word: &Word) -> Word {
-        let syllables = word.syllables();
-        let mut it = syllables.iter().peekable();
-        let mut res = String::new();
+    /// Creates a strict pronouncer for phonetic transcriptions of running
+    /// text, i.e., without stem tensing.
+    pub(crate) fn phonetic() -> Pronouncer {
+        Pronouncer::new(true).stem_tensing(false)
+    }
 
-        let mut s = it.next().cloned();
-        let mut t = it.next().cloned();
+    /// Sets whether verb stem tensification (rules 24 and 25) is reflected
+    /// when `strict`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hangeul::{Pronouncer, Word};
+    ///
+    /// let word = Word::new("신고").unwrap();
+    /// let pronouncer = Pronouncer::new(true);
+    /// assert_eq!(pronouncer.pronounce(&word).to_string(), "신꼬");
+    /// assert_eq!(pronouncer.stem_tensing(false).pronounce(&word).to_string(), "신고");
+    /// ```
+    pub fn stem_tensing(mut self, stem_tensing: bool) -> Pronouncer {
+        self.stem_tensing = stem_tensing;
+        self
+    }
 
-        while let Some(u) = s {
-            let v = t;
+    /// Returns the standard pronunciation of a word.
+    pub fn pronounce(&self, word: &Word) -> Word {
+        let mut res = word.clone();
+        self.pronounce_in_place(&mut res);
+        res
+    }
 
-            let v = rule_16(u, v);
-            let (u, v) = rule_17(u, v);
-            let u = rule_18(u, v);
-            let (u, v) = rule_19(u, v);
+    /// Replaces a word with its standard pronunciation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hangeul::{Pronouncer, Word};
+    ///
+    /// let mut word = Word::new("한국어").unwrap();
+    /// Pronouncer::new(false).pronounce_in_place(&mut word);
+    /// assert_eq!(word.to_string(), "한구거");
+    /// ```
+    pub fn pronounce_in_place(&self, word: &mut Word) {
+        let mut syllables = word.syllables_mut();
+
+        let table = Table::get(self);
+
+        for i in 0..syllables.len() {
+            let u = syllables[i];
+            let v = syllables.get(i + 1).copied();
+
+            // Rule 16 only applies to the names of letters (e.g., 디귿).
+            let v = if u.jongseong().is_none() {
+                rule_16(u, v)
+            } else {
+                v
+            };
 
-            let (u, v) = rule_24(self, u, v);
-            let (u, v) = rule_25(self, u, v);
+            let (u, v) = table.apply(u, v);
 
+            // Rule 5 only depends on the vowel of the syllable, which no
+            // other rule reads or writes.
             let u = if self.strict {
                 let u = rule_05_1(u);
-                let u = rule_05_3(u);
-                u
+                rule_05_3(u)
             } else {
                 u
             };
 
-            let (u, v) = rule_12_1(u, v);
-
-            let (u, v) = if self.strict {
-                let (u, v) = rule_12_1_1(u, v);
-                let (u, v) = rule_12_1_2(u, v);
-                (u, v)
-            } else {
-                (u, v)
-            };
-
-            let (u, v) = rule_12_2(u, v);
-            let (u, v) = rule_12_3(u, v);
-            let (u, v) = rule_12_4(u, v);
-
-            let u = rule_09(u, v);
-            let u = rule_10(u, v);
-            let u = rule_11(u, v);
-
-            let (u, v) = rule_14(self, u, v);
-            let (u, v) = rule_20(u, v);
-            let (u, v) = rule_23(self, u, v);
-
-            let (u, v) = rule_13(u, v);
-
-            res.push(u.as_char());
+            syllables[i] = u;
 
-            s = v;
-            t = it.next().cloned();
+            if let Some(v) = v {
+                syllables[i + 1] = v;
+            }
         }
-
-        Word::new(res)
     }
 
     /// Transforms the given plain consonant into a tensed one.
     ///
-    /// This is effectively no-op when `strict` is false.
-    pub fn reflect_tense(&self, j: char) -> char {
-        if !self.strict {
-            match j {
-                'ㄱ' | 'ㄷ' | 'ㅂ' | 'ㅅ' | 'ㅈ' => j,
-                _ => unreachable!(),
-            }
+    /// This is effectively no-op when `strict` is false. Consonants without
+    /// a tensed form are returned as is.
+    pub fn reflect_tense(&self, j: Choseong) -> Choseong {
+        self.tense(j)
+    }
+
+    /// Transforms the given plain consonant into a tensed one after the
+    /// final consonant of a verb stem.
+    ///
+    /// This is effectively no-op when `strict` or stem tensing is false.
+    pub(crate) fn reflect_stem_tense(&self, j: Choseong) -> Choseong {
+        if self.stem_tensing {
+            self.tense(j)
         } else {
-            match j {
-                'ㄱ' => 'ㄲ',
-                'ㄷ' => 'ㄸ',
-                'ㅂ' => 'ㅃ',
-                'ㅅ' => 'ㅆ',
-                'ㅈ' => 'ㅉ',
-                _ => unreachable!(),
-            }
+            j
+        }
+    }
+
+    fn tense(&self, j: Choseong) -> Choseong {
+        if !self.strict {
+            return j;
+        }
+
+        match j {
+            Choseong::Giyeok => Choseong::SsangGiyeok,
+            Choseong::Digeut => Choseong::SsangDigeut,
+            Choseong::Bieup => Choseong::SsangBieup,
+            Choseong::Siot => Choseong::SsangSiot,
+            Choseong::Jieut => Choseong::SsangJieut,
+            _ => j,
         }
     }
 }
@@ -100,7 +157,7 @@ mod tests {
     use crate::Word;
 
     fn p(s: &str) -> String {
-        let word = Word::new(s);
+        let word = Word::new(s).unwrap();
         let pronouncer = Pronouncer::new(true);
         pronouncer.pronounce(&word).to_string()
     }
@@ -1,12 +1,14 @@
+#![allow(clippy::unnecessary_map_or)]
+
 use crate::pronunciation::Pronouncer;
-use crate::Syllable;
+use crate::{Choseong, Jongseong, Jungseong, Syllable};
 
 pub fn rule_05_1(mut s: Syllable) -> Syllable {
     match s.choseong() {
-        'ㅈ' | 'ㅉ' | 'ㅊ' => {
-            if s.jungseong() == 'ㅕ' {
-                s.set_jungseong('ㅓ');
-            }
+        Choseong::Jieut | Choseong::SsangJieut | Choseong::Chieut
+            if s.jungseong() == Jungseong::Yeo =>
+        {
+            s.set_jungseong(Jungseong::Eo);
         }
         _ => {}
     }
@@ -15,8 +17,8 @@ pub fn rule_05_1(mut s: Syllable) -> Syllable {
 }
 
 pub fn rule_05_3(mut s: Syllable) -> Syllable {
-    if s.choseong() != 'ㅇ' && s.jungseong() == 'ㅢ' {
-        s.set_jungseong('ㅣ');
+    if s.choseong() != Choseong::Ieung && s.jungseong() == Jungseong::Ui {
+        s.set_jungseong(Jungseong::I);
     }
 
     s
@@ -24,11 +26,15 @@ pub fn rule_05_3(mut s: Syllable) -> Syllable {
 
 pub fn rule_09(mut s: Syllable, t: Option<Syllable>) -> Syllable {
     if let Some(j) = s.jongseong() {
-        if t.map_or(true, |t| t.choseong() != 'ㅇ') {
+        if t.map_or(true, |t| t.choseong() != Choseong::Ieung) {
             let k = match j {
-                'ㄲ' | 'ㅋ' => Some('ㄱ'),
-                'ㅅ' | 'ㅆ' | 'ㅈ' | 'ㅊ' | 'ㅌ' => Some('ㄷ'),
-                'ㅍ' => Some('ㅂ'),
+                Jongseong::SsangGiyeok | Jongseong::Kieuk => Some(Jongseong::Giyeok),
+                Jongseong::Siot
+                | Jongseong::SsangSiot
+                | Jongseong::Jieut
+                | Jongseong::Chieut
+                | Jongseong::Tieut => Some(Jongseong::Digeut),
+                Jongseong::Pieup => Some(Jongseong::Bieup),
                 _ => None,
             };
 
@@ -43,19 +49,19 @@ pub fn rule_09(mut s: Syllable, t: Option<Syllable>) -> Syllable {
 
 pub fn rule_10(mut s: Syllable, t: Option<Syllable>) -> Syllable {
     if let Some(j) = s.jongseong() {
-        if t.map_or(true, |t| t.choseong() != 'ㅇ') {
+        if t.map_or(true, |t| t.choseong() != Choseong::Ieung) {
             let k = match j {
-                'ㄳ' => Some('ㄱ'),
-                'ㄵ' => Some('ㄴ'),
-                'ㄼ' => {
-                    if s.choseong() == 'ㅂ' && s.jungseong() == 'ㅏ' {
-                        Some('ㅂ')
+                Jongseong::GiyeokSiot => Some(Jongseong::Giyeok),
+                Jongseong::NieunJieut => Some(Jongseong::Nieun),
+                Jongseong::RieulBieup => {
+                    if s.choseong() == Choseong::Bieup && s.jungseong() == Jungseong::A {
+                        Some(Jongseong::Bieup)
                     } else {
-                        Some('ㄹ')
+                        Some(Jongseong::Rieul)
                     }
                 }
-                'ㄽ' | 'ㄾ' => Some('ㄹ'),
-                'ㅄ' => Some('ㅂ'),
+                Jongseong::RieulSiot | Jongseong::RieulTieut => Some(Jongseong::Rieul),
+                Jongseong::BieupSiot => Some(Jongseong::Bieup),
                 _ => None,
             };
 
@@ -70,11 +76,11 @@ pub fn rule_10(mut s: Syllable, t: Option<Syllable>) -> Syllable {
 
 pub fn rule_11(mut s: Syllable, t: Option<Syllable>) -> Syllable {
     if let Some(j) = s.jongseong() {
-        if t.map_or(true, |t| t.choseong() != 'ㅇ') {
+        if t.map_or(true, |t| t.choseong() != Choseong::Ieung) {
             let k = match j {
-                'ㄺ' => Some('ㄱ'),
-                'ㄻ' => Some('ㅁ'),
-                'ㄿ' => Some('ㅂ'),
+                Jongseong::RieulGiyeok => Some(Jongseong::Giyeok),
+                Jongseong::RieulMieum => Some(Jongseong::Mieum),
+                Jongseong::RieulPieup => Some(Jongseong::Bieup),
                 _ => None,
             };
 
@@ -90,29 +96,21 @@ pub fn rule_11(mut s: Syllable, t: Option<Syllable>) -> Syllable {
 pub fn rule_12_1(mut s: Syllable, t: Option<Syllable>) -> (Syllable, Option<Syllable>) {
     if let Some(j) = s.jongseong() {
         if let Some(mut t) = t {
-            let km = match j {
-                'ㅎ' => match t.choseong() {
-                    'ㄱ' => Some((None, 'ㅋ')),
-                    'ㄷ' => Some((None, 'ㅌ')),
-                    'ㅈ' => Some((None, 'ㅊ')),
-                    _ => None,
-                },
-                'ㄶ' => match t.choseong() {
-                    'ㄱ' => Some((Some('ㄴ'), 'ㅋ')),
-                    'ㄷ' => Some((Some('ㄴ'), 'ㅌ')),
-                    'ㅈ' => Some((Some('ㄴ'), 'ㅊ')),
-                    _ => None,
-                },
-                'ㅀ' => match t.choseong() {
-                    'ㄱ' => Some((Some('ㄹ'), 'ㅋ')),
-                    'ㄷ' => Some((Some('ㄹ'), 'ㅌ')),
-                    'ㅈ' => Some((Some('ㄹ'), 'ㅊ')),
-                    _ => None,
-                },
+            let k = match j {
+                Jongseong::Hieut => Some(None),
+                Jongseong::NieunHieut => Some(Some(Jongseong::Nieun)),
+                Jongseong::RieulHieut => Some(Some(Jongseong::Rieul)),
                 _ => None,
             };
 
-            if let Some((k, m)) = km {
+            let m = match t.choseong() {
+                Choseong::Giyeok => Some(Choseong::Kieuk),
+                Choseong::Digeut => Some(Choseong::Tieut),
+                Choseong::Jieut => Some(Choseong::Chieut),
+                _ => None,
+            };
+
+            if let (Some(k), Some(m)) = (k, m) {
                 s.set_jongseong(k);
                 t.set_choseong(m);
                 return (s, Some(t));
@@ -126,15 +124,15 @@ pub fn rule_12_1(mut s: Syllable, t: Option<Syllable>) -> (Syllable, Option<Syll
 pub fn rule_12_1_1(mut s: Syllable, t: Option<Syllable>) -> (Syllable, Option<Syllable>) {
     if let Some(j) = s.jongseong() {
         if let Some(mut t) = t {
-            if t.choseong() == 'ㅎ' {
+            if t.choseong() == Choseong::Hieut {
                 let km = match j {
-                    'ㄱ' => Some((None, 'ㅋ')),
-                    'ㄺ' => Some((Some('ㄹ'), 'ㅋ')),
-                    'ㄷ' => Some((None, 'ㅌ')),
-                    'ㅂ' => Some((None, 'ㅍ')),
-                    'ㄼ' => Some((Some('ㄹ'), 'ㅍ')),
-                    'ㅈ' => Some((None, 'ㅊ')),
-                    'ㄵ' => Some((Some('ㄴ'), 'ㅊ')),
+                    Jongseong::Giyeok => Some((None, Choseong::Kieuk)),
+                    Jongseong::RieulGiyeok => Some((Some(Jongseong::Rieul), Choseong::Kieuk)),
+                    Jongseong::Digeut => Some((None, Choseong::Tieut)),
+                    Jongseong::Bieup => Some((None, Choseong::Pieup)),
+                    Jongseong::RieulBieup => Some((Some(Jongseong::Rieul), Choseong::Pieup)),
+                    Jongseong::Jieut => Some((None, Choseong::Chieut)),
+                    Jongseong::NieunJieut => Some((Some(Jongseong::Nieun), Choseong::Chieut)),
                     _ => None,
                 };
 
@@ -153,11 +151,11 @@ pub fn rule_12_1_1(mut s: Syllable, t: Option<Syllable>) -> (Syllable, Option<Sy
 pub fn rule_12_1_2(mut s: Syllable, t: Option<Syllable>) -> (Syllable, Option<Syllable>) {
     if let Some(j) = s.jongseong() {
         if let Some(mut t) = t {
-            if t.choseong() == 'ㅎ' {
+            if t.choseong() == Choseong::Hieut {
                 match j {
-                    'ㅅ' | /* 'ㅈ' | */ 'ㅊ' | 'ㅌ' => {
+                    Jongseong::Siot | /* Jongseong::Jieut | */ Jongseong::Chieut | Jongseong::Tieut => {
                         s.set_jongseong(None);
-                        t.set_choseong('ㅌ');
+                        t.set_choseong(Choseong::Tieut);
                         return (s, Some(t));
                     },
                     _ => {}
@@ -172,17 +170,17 @@ pub fn rule_12_1_2(mut s: Syllable, t: Option<Syllable>) -> (Syllable, Option<Sy
 pub fn rule_12_2(mut s: Syllable, t: Option<Syllable>) -> (Syllable, Option<Syllable>) {
     if let Some(j) = s.jongseong() {
         if let Some(mut t) = t {
-            if t.choseong() == 'ㅅ' {
+            if t.choseong() == Choseong::Siot {
                 let k = match j {
-                    'ㅎ' => Some(None),
-                    'ㄶ' => Some(Some('ㄴ')),
-                    'ㅀ' => Some(Some('ㄹ')),
+                    Jongseong::Hieut => Some(None),
+                    Jongseong::NieunHieut => Some(Some(Jongseong::Nieun)),
+                    Jongseong::RieulHieut => Some(Some(Jongseong::Rieul)),
                     _ => None,
                 };
 
                 if let Some(k) = k {
                     s.set_jongseong(k);
-                    t.set_choseong('ㅆ');
+                    t.set_choseong(Choseong::SsangSiot);
                     return (s, Some(t));
                 }
             }
@@ -195,11 +193,11 @@ pub fn rule_12_2(mut s: Syllable, t: Option<Syllable>) -> (Syllable, Option<Syll
 pub fn rule_12_3(mut s: Syllable, t: Option<Syllable>) -> (Syllable, Option<Syllable>) {
     if let Some(j) = s.jongseong() {
         if let Some(t) = t {
-            if t.choseong() == 'ㄴ' {
+            if t.choseong() == Choseong::Nieun {
                 let k = match j {
-                    'ㅎ' => Some('ㄴ'),
-                    'ㄶ' => Some('ㄴ'),
-                    'ㅀ' => Some('ㄹ'),
+                    Jongseong::Hieut => Some(Jongseong::Nieun),
+                    Jongseong::NieunHieut => Some(Jongseong::Nieun),
+                    Jongseong::RieulHieut => Some(Jongseong::Rieul),
                     _ => None,
                 };
 
@@ -217,11 +215,11 @@ pub fn rule_12_3(mut s: Syllable, t: Option<Syllable>) -> (Syllable, Option<Syll
 pub fn rule_12_4(mut s: Syllable, t: Option<Syllable>) -> (Syllable, Option<Syllable>) {
     if let Some(j) = s.jongseong() {
         if let Some(mut t) = t {
-            if t.choseong() == 'ㅇ' {
+            if t.choseong() == Choseong::Ieung {
                 let k = match j {
-                    'ㅎ' => Some('ㅇ'),
-                    'ㄶ' => Some('ㄴ'),
-                    'ㅀ' => Some('ㄹ'),
+                    Jongseong::Hieut => Some(Choseong::Ieung),
+                    Jongseong::NieunHieut => Some(Choseong::Nieun),
+                    Jongseong::RieulHieut => Some(Choseong::Rieul),
                     _ => None,
                 };
 
@@ -239,11 +237,13 @@ pub fn rule_12_4(mut s: Syllable, t: Option<Syllable>) -> (Syllable, Option<Syll
 
 pub fn rule_13(mut s: Syllable, t: Option<Syllable>) -> (Syllable, Option<Syllable>) {
     if let Some(j) = s.jongseong() {
-        if j != 'ㅇ' {
+        if j != Jongseong::Ieung {
             if let Some(mut t) = t {
-                if t.choseong() == 'ㅇ' {
+                // Consonant clusters, which have no initial form, are split
+                // by rules 12 and 14 beforehand.
+                if let (Choseong::Ieung, Some(k)) = (t.choseong(), j.to_choseong()) {
                     s.set_jongseong(None);
-                    t.set_choseong(j);
+                    t.set_choseong(k);
                     return (s, Some(t));
                 }
             }
@@ -260,19 +260,25 @@ pub fn rule_14(
 ) -> (Syllable, Option<Syllable>) {
     if let Some(j) = s.jongseong() {
         if let Some(mut t) = t {
-            if t.choseong() == 'ㅇ' {
+            if t.choseong() == Choseong::Ieung {
                 let km = match j {
-                    'ㄳ' => Some(('ㄱ', ctx.reflect_tense('ㅅ'))),
-                    'ㄵ' => Some(('ㄴ', 'ㅈ')),
-                    'ㄶ' => Some(('ㄴ', 'ㅎ')),
-                    'ㄺ' => Some(('ㄹ', 'ㄱ')),
-                    'ㄻ' => Some(('ㄹ', 'ㅁ')),
-                    'ㄼ' => Some(('ㄹ', 'ㅂ')),
-                    'ㄽ' => Some(('ㄹ', ctx.reflect_tense('ㅅ'))),
-                    'ㄾ' => Some(('ㄹ', 'ㅌ')),
-                    'ㄿ' => Some(('ㄹ', 'ㅍ')),
-                    'ㅀ' => Some(('ㄹ', 'ㅎ')),
-                    'ㅄ' => Some(('ㅂ', ctx.reflect_tense('ㅅ'))),
+                    Jongseong::GiyeokSiot => {
+                        Some((Jongseong::Giyeok, ctx.reflect_tense(Choseong::Siot)))
+                    }
+                    Jongseong::NieunJieut => Some((Jongseong::Nieun, Choseong::Jieut)),
+                    Jongseong::NieunHieut => Some((Jongseong::Nieun, Choseong::Hieut)),
+                    Jongseong::RieulGiyeok => Some((Jongseong::Rieul, Choseong::Giyeok)),
+                    Jongseong::RieulMieum => Some((Jongseong::Rieul, Choseong::Mieum)),
+                    Jongseong::RieulBieup => Some((Jongseong::Rieul, Choseong::Bieup)),
+                    Jongseong::RieulSiot => {
+                        Some((Jongseong::Rieul, ctx.reflect_tense(Choseong::Siot)))
+                    }
+                    Jongseong::RieulTieut => Some((Jongseong::Rieul, Choseong::Tieut)),
+                    Jongseong::RieulPieup => Some((Jongseong::Rieul, Choseong::Pieup)),
+                    Jongseong::RieulHieut => Some((Jongseong::Rieul, Choseong::Hieut)),
+                    Jongseong::BieupSiot => {
+                        Some((Jongseong::Bieup, ctx.reflect_tense(Choseong::Siot)))
+                    }
                     _ => None,
                 };
 
@@ -291,13 +297,13 @@ pub fn rule_14(
 pub fn rule_16(s: Syllable, t: Option<Syllable>) -> Option<Syllable> {
     if let Some(mut t) = t {
         let j = match (s.as_char(), t.as_char()) {
-            ('디', '귿') => Some('ㅅ'),
-            ('지', '읒') => Some('ㅅ'),
-            ('치', '읓') => Some('ㅅ'),
-            ('키', '읔') => Some('ㄱ'),
-            ('티', '읕') => Some('ㅅ'),
-            ('피', '읖') => Some('ㅂ'),
-            ('히', '읗') => Some('ㅅ'),
+            ('디', '귿') => Some(Jongseong::Siot),
+            ('지', '읒') => Some(Jongseong::Siot),
+            ('치', '읓') => Some(Jongseong::Siot),
+            ('키', '읔') => Some(Jongseong::Giyeok),
+            ('티', '읕') => Some(Jongseong::Siot),
+            ('피', '읖') => Some(Jongseong::Bieup),
+            ('히', '읗') => Some(Jongseong::Siot),
             _ => None,
         };
 
@@ -314,14 +320,14 @@ pub fn rule_17(mut s: Syllable, t: Option<Syllable>) -> (Syllable, Option<Syllab
     if let Some(j) = s.jongseong() {
         if let Some(mut t) = t {
             let km = match (t.choseong(), t.jungseong()) {
-                ('ㅇ', 'ㅣ') => match j {
-                    'ㄷ' => Some((None, 'ㅈ')),
-                    'ㅌ' => Some((None, 'ㅊ')),
-                    'ㄾ' => Some((Some('ㄹ'), 'ㅊ')),
+                (Choseong::Ieung, Jungseong::I) => match j {
+                    Jongseong::Digeut => Some((None, Choseong::Jieut)),
+                    Jongseong::Tieut => Some((None, Choseong::Chieut)),
+                    Jongseong::RieulTieut => Some((Some(Jongseong::Rieul), Choseong::Chieut)),
                     _ => None,
                 },
-                ('ㅎ', 'ㅣ') => match j {
-                    'ㄷ' => Some((None, 'ㅊ')),
+                (Choseong::Hieut, Jungseong::I) => match j {
+                    Jongseong::Digeut => Some((None, Choseong::Chieut)),
                     _ => None,
                 },
                 _ => None,
@@ -340,11 +346,27 @@ pub fn rule_17(mut s: Syllable, t: Option<Syllable>) -> (Syllable, Option<Syllab
 
 pub fn rule_18(mut s: Syllable, t: Option<Syllable>) -> Syllable {
     if let Some(j) = s.jongseong() {
-        if t.map_or(false, |t| t.choseong() == 'ㄴ' || t.choseong() == 'ㅁ') {
+        if t.map_or(false, |t| {
+            t.choseong() == Choseong::Nieun || t.choseong() == Choseong::Mieum
+        }) {
             let k = match j {
-                'ㄱ' | 'ㄲ' | 'ㅋ' | 'ㄳ' | 'ㄺ' => Some('ㅇ'),
-                'ㄷ' | 'ㅅ' | 'ㅆ' | 'ㅈ' | 'ㅊ' | 'ㅌ' | 'ㅎ' => Some('ㄴ'),
-                'ㅂ' | 'ㅍ' | 'ㄼ' | 'ㄿ' | 'ㅄ' => Some('ㅁ'),
+                Jongseong::Giyeok
+                | Jongseong::SsangGiyeok
+                | Jongseong::Kieuk
+                | Jongseong::GiyeokSiot
+                | Jongseong::RieulGiyeok => Some(Jongseong::Ieung),
+                Jongseong::Digeut
+                | Jongseong::Siot
+                | Jongseong::SsangSiot
+                | Jongseong::Jieut
+                | Jongseong::Chieut
+                | Jongseong::Tieut
+                | Jongseong::Hieut => Some(Jongseong::Nieun),
+                Jongseong::Bieup
+                | Jongseong::Pieup
+                | Jongseong::RieulBieup
+                | Jongseong::RieulPieup
+                | Jongseong::BieupSiot => Some(Jongseong::Mieum),
                 _ => None,
             };
 
@@ -360,17 +382,17 @@ pub fn rule_18(mut s: Syllable, t: Option<Syllable>) -> Syllable {
 pub fn rule_19(mut s: Syllable, t: Option<Syllable>) -> (Syllable, Option<Syllable>) {
     if let Some(j) = s.jongseong() {
         if let Some(mut t) = t {
-            if t.choseong() == 'ㄹ' {
+            if t.choseong() == Choseong::Rieul {
                 let k = match j {
-                    'ㅁ' | 'ㅇ' => Some(j),
-                    'ㄱ' => Some('ㅇ'),
-                    'ㅂ' => Some('ㅁ'),
+                    Jongseong::Mieum | Jongseong::Ieung => Some(j),
+                    Jongseong::Giyeok => Some(Jongseong::Ieung),
+                    Jongseong::Bieup => Some(Jongseong::Mieum),
                     _ => None,
                 };
 
                 if k.is_some() {
                     s.set_jongseong(k);
-                    t.set_choseong('ㄴ');
+                    t.set_choseong(Choseong::Nieun);
                     return (s, Some(t));
                 }
             }
@@ -385,9 +407,11 @@ pub fn rule_20(mut s: Syllable, t: Option<Syllable>) -> (Syllable, Option<Syllab
         if let Some(mut t) = t {
             let k = t.choseong();
 
-            if (j == 'ㄴ' && k == 'ㄹ') || (j == 'ㄹ' && k == 'ㄴ') {
-                s.set_jongseong(Some('ㄹ'));
-                t.set_choseong('ㄹ');
+            if (j == Jongseong::Nieun && k == Choseong::Rieul)
+                || (j == Jongseong::Rieul && k == Choseong::Nieun)
+            {
+                s.set_jongseong(Some(Jongseong::Rieul));
+                t.set_choseong(Choseong::Rieul);
                 return (s, Some(t));
             }
         }
@@ -402,9 +426,27 @@ pub fn rule_23(ctx: &Pronouncer, s: Syllable, t: Option<Syllable>) -> (Syllable,
             let k = t.choseong();
 
             let m = match j {
-                'ㄱ' | 'ㄲ' | 'ㅋ' | 'ㄳ' | 'ㄺ' | 'ㄷ' | 'ㅅ' | 'ㅆ' | 'ㅈ' | 'ㅊ'
-                | 'ㅌ' | 'ㅂ' | 'ㅍ' | 'ㄼ' | 'ㄿ' | 'ㅄ' => match k {
-                    'ㄱ' | 'ㄷ' | 'ㅂ' | 'ㅅ' | 'ㅈ' => Some(ctx.reflect_tense(k)),
+                Jongseong::Giyeok
+                | Jongseong::SsangGiyeok
+                | Jongseong::Kieuk
+                | Jongseong::GiyeokSiot
+                | Jongseong::RieulGiyeok
+                | Jongseong::Digeut
+                | Jongseong::Siot
+                | Jongseong::SsangSiot
+                | Jongseong::Jieut
+                | Jongseong::Chieut
+                | Jongseong::Tieut
+                | Jongseong::Bieup
+                | Jongseong::Pieup
+                | Jongseong::RieulBieup
+                | Jongseong::RieulPieup
+                | Jongseong::BieupSiot => match k {
+                    Choseong::Giyeok
+                    | Choseong::Digeut
+                    | Choseong::Bieup
+                    | Choseong::Siot
+                    | Choseong::Jieut => Some(ctx.reflect_tense(k)),
                     _ => None,
                 },
                 _ => None,
@@ -430,10 +472,10 @@ pub fn rule_24(
             let k = t.choseong();
 
             let km = match k {
-                'ㄱ' | 'ㄷ' | 'ㅅ' | 'ㅈ' => match j {
-                    'ㄴ' | 'ㅁ' => Some((j, ctx.reflect_tense(k))),
-                    'ㄵ' => Some(('ㄴ', ctx.reflect_tense(k))),
-                    'ㄻ' => Some(('ㅁ', ctx.reflect_tense(k))),
+                Choseong::Giyeok | Choseong::Digeut | Choseong::Siot | Choseong::Jieut => match j {
+                    Jongseong::Nieun | Jongseong::Mieum => Some((j, ctx.reflect_stem_tense(k))),
+                    Jongseong::NieunJieut => Some((Jongseong::Nieun, ctx.reflect_stem_tense(k))),
+                    Jongseong::RieulMieum => Some((Jongseong::Mieum, ctx.reflect_stem_tense(k))),
                     _ => None,
                 },
                 _ => None,
@@ -456,8 +498,10 @@ pub fn rule_25(ctx: &Pronouncer, s: Syllable, t: Option<Syllable>) -> (Syllable,
             let k = t.choseong();
 
             let m = match j {
-                'ㄼ' | 'ㄾ' => match k {
-                    'ㄱ' | 'ㄷ' | 'ㅅ' | 'ㅈ' => Some(ctx.reflect_tense(k)),
+                Jongseong::RieulBieup | Jongseong::RieulTieut => match k {
+                    Choseong::Giyeok | Choseong::Digeut | Choseong::Siot | Choseong::Jieut => {
+                        Some(ctx.reflect_stem_tense(k))
+                    }
                     _ => None,
                 },
                 _ => None,
@@ -0,0 +1,265 @@
+use std::sync::OnceLock;
+
+use super::rules::*;
+use crate::{Choseong, Jongseong, Jungseong, Pronouncer, Syllable};
+
+// Final consonant, or none.
+const JONGSEONGS_LEN: usize = 28;
+// Initial consonant of the next syllable, or no next syllable.
+const NEXT_LEN: usize = 20;
+
+const TABLE_LEN: usize = JONGSEONGS_LEN * NEXT_LEN * 2 * 2;
+
+/// The outcome of the rules of standard pronunciation on a pair of
+/// syllables
+///
+/// Apart from rules 5 and 16, the rules only read the final consonant of a
+/// syllable, the initial consonant of the next syllable, and whether the
+/// next vowel is ㅣ (rule 17). The one exception is 밟 (rule 10). They only
+/// write the same consonants. Every combination is thus precomputed by
+/// running the rules once on representative syllables.
+pub struct Table {
+    entries: Vec<(u8, u8)>,
+}
+
+impl Table {
+    /// Returns the table for the given pronouncer, building it on first use.
+    pub fn get(pronouncer: &Pronouncer) -> &'static Table {
+        static TABLES: [OnceLock<Table>; 4] = [
+            OnceLock::new(),
+            OnceLock::new(),
+            OnceLock::new(),
+            OnceLock::new(),
+        ];
+
+        let i = usize::from(pronouncer.strict) * 2 + usize::from(pronouncer.stem_tensing);
+        TABLES[i].get_or_init(|| Table::build(pronouncer))
+    }
+
+    fn build(pronouncer: &Pronouncer) -> Table {
+        let mut entries = vec![(0, 0); TABLE_LEN];
+
+        for m in 0..JONGSEONGS_LEN {
+            for n in 0..NEXT_LEN {
+                for &is_i in &[false, true] {
+                    for &is_bap in &[false, true] {
+                        let (j, k) = if is_bap {
+                            (Choseong::Bieup, Jungseong::A)
+                        } else {
+                            (Choseong::Giyeok, Jungseong::Eo)
+                        };
+                        let u = Syllable::new(j, k, jongseong(m));
+
+                        let v = choseong(n).map(|j| {
+                            let k = if is_i { Jungseong::I } else { Jungseong::A };
+                            Syllable::new(j, k, None)
+                        });
+
+                        let (u, v) = pronouncer.apply_pair_rules(u, v);
+
+                        let m_out = jongseong_index(u.jongseong());
+                        let n_out = choseong_index(v.map(|v| v.choseong()));
+
+                        entries[index(m, n, is_i, is_bap)] = (m_out as u8, n_out as u8);
+                    }
+                }
+            }
+        }
+
+        Table { entries }
+    }
+
+    /// Applies the rules (except 5 and 16) to a syllable and the next one.
+    pub fn apply(&self, mut u: Syllable, v: Option<Syllable>) -> (Syllable, Option<Syllable>) {
+        let m = jongseong_index(u.jongseong());
+        let n = choseong_index(v.map(|v| v.choseong()));
+        let is_i = v.is_some_and(|v| v.jungseong() == Jungseong::I);
+        let is_bap = u.choseong() == Choseong::Bieup && u.jungseong() == Jungseong::A;
+
+        let (m_out, n_out) = self.entries[index(m, n, is_i, is_bap)];
+
+        u.set_jongseong(jongseong(usize::from(m_out)));
+
+        let v = v.map(|mut v| {
+            if let Some(j) = choseong(usize::from(n_out)) {
+                v.set_choseong(j);
+            }
+
+            v
+        });
+
+        (u, v)
+    }
+}
+
+impl Pronouncer {
+    /// Applies the rules that depend on a pair of syllables, i.e., all but
+    /// rules 5 and 16, in order.
+    fn apply_pair_rules(&self, u: Syllable, v: Option<Syllable>) -> (Syllable, Option<Syllable>) {
+        let (u, v) = rule_17(u, v);
+        let u = rule_18(u, v);
+        let (u, v) = rule_19(u, v);
+
+        let (u, v) = rule_24(self, u, v);
+        let (u, v) = rule_25(self, u, v);
+
+        let (u, v) = rule_12_1(u, v);
+
+        let (u, v) = if self.strict {
+            let (u, v) = rule_12_1_1(u, v);
+            let (u, v) = rule_12_1_2(u, v);
+            (u, v)
+        } else {
+            (u, v)
+        };
+
+        let (u, v) = rule_12_2(u, v);
+        let (u, v) = rule_12_3(u, v);
+        let (u, v) = rule_12_4(u, v);
+
+        let u = rule_09(u, v);
+        let u = rule_10(u, v);
+        let u = rule_11(u, v);
+
+        let (u, v) = rule_14(self, u, v);
+        let (u, v) = rule_20(u, v);
+        let (u, v) = rule_23(self, u, v);
+
+        rule_13(u, v)
+    }
+}
+
+fn index(m: usize, n: usize, is_i: bool, is_bap: bool) -> usize {
+    ((m * NEXT_LEN + n) * 2 + usize::from(is_i)) * 2 + usize::from(is_bap)
+}
+
+fn choseong_index(j: Option<Choseong>) -> usize {
+    j.map_or(0, |j| j.index() + 1)
+}
+
+fn choseong(i: usize) -> Option<Choseong> {
+    i.checked_sub(1).and_then(Choseong::from_index)
+}
+
+fn jongseong_index(m: Option<Jongseong>) -> usize {
+    m.map_or(0, |m| m.index() + 1)
+}
+
+fn jongseong(i: usize) -> Option<Jongseong> {
+    i.checked_sub(1).and_then(Jongseong::from_index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The rules applied to a syllable and the next one, as in the original
+    /// rule chain.
+    fn step(p: &Pronouncer, u: Syllable, v: Option<Syllable>) -> (Syllable, Option<Syllable>) {
+        let v = rule_16(u, v);
+        let (u, v) = rule_17(u, v);
+        let u = rule_18(u, v);
+        let (u, v) = rule_19(u, v);
+
+        let (u, v) = rule_24(p, u, v);
+        let (u, v) = rule_25(p, u, v);
+
+        let u = if p.strict {
+            let u = rule_05_1(u);
+            rule_05_3(u)
+        } else {
+            u
+        };
+
+        let (u, v) = rule_12_1(u, v);
+
+        let (u, v) = if p.strict {
+            let (u, v) = rule_12_1_1(u, v);
+            let (u, v) = rule_12_1_2(u, v);
+            (u, v)
+        } else {
+            (u, v)
+        };
+
+        let (u, v) = rule_12_2(u, v);
+        let (u, v) = rule_12_3(u, v);
+        let (u, v) = rule_12_4(u, v);
+
+        let u = rule_09(u, v);
+        let u = rule_10(u, v);
+        let u = rule_11(u, v);
+
+        let (u, v) = rule_14(p, u, v);
+        let (u, v) = rule_20(u, v);
+        let (u, v) = rule_23(p, u, v);
+
+        rule_13(u, v)
+    }
+
+    fn all_syllables() -> impl Iterator<Item = Syllable> {
+        (0xac00..=0xd7a3)
+            .filter_map(std::char::from_u32)
+            .filter_map(|c| Syllable::from_char(c).ok())
+    }
+
+    /// Compares the table against the rules for every syllable followed by
+    /// no syllable or by every combination of initial consonant and vowel.
+    ///
+    /// The final consonant of the next syllable is only read by rule 16,
+    /// which is applied as is, so final consonants are only covered for the
+    /// letter names that rule matches (e.g., 디귿).
+    #[test]
+    fn test_table_matches_rules() {
+        let mut nexts = vec![None];
+
+        for &j in Choseong::ALL.iter() {
+            for &k in Jungseong::ALL.iter() {
+                nexts.push(Some(Syllable::new(j, k, None)));
+            }
+        }
+
+        for &c in &['귿', '읒', '읓', '읔', '읕', '읖', '읗'] {
+            nexts.push(Syllable::from_char(c).ok());
+        }
+
+        let pronouncers = [
+            Pronouncer::new(false),
+            Pronouncer::new(true),
+            Pronouncer::phonetic(),
+        ];
+
+        for pronouncer in &pronouncers {
+            let strict = pronouncer.strict;
+            let table = Table::get(pronouncer);
+
+            for u in all_syllables() {
+                for &v in &nexts {
+                    let expected = step(pronouncer, u, v);
+
+                    let w = if u.jongseong().is_none() {
+                        rule_16(u, v)
+                    } else {
+                        v
+                    };
+
+                    let (actual_u, actual_v) = table.apply(u, w);
+
+                    let actual_u = if strict {
+                        rule_05_3(rule_05_1(actual_u))
+                    } else {
+                        actual_u
+                    };
+
+                    assert_eq!(
+                        (actual_u, actual_v),
+                        expected,
+                        "pronouncer = {:?}, u = {:?}, v = {:?}",
+                        pronouncer,
+                        u,
+                        v
+                    );
+                }
+            }
+        }
+    }
+}
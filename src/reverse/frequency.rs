@@ -0,0 +1,41 @@
+//! Rough frequencies of syllables in written Korean, used to rank
+//! candidates that are otherwise equally valid readings.
+
+static COMMON: &str = "이다는의에하고가을지기서를사로한어리도대자인아수정일나시적으해있들내게조\
+전부문주보상제원국과장성동오유우만소세구학회연경되생거면라그화여신마방공선말요습십합";
+
+static FREQUENT: &str = "위히것물저실계중발개진분간식니무비행천반년안영민명통스용강종랑녕울운미\
+모입음속산심감금느드르러려럼께까네데래레새배매애때없좋같알살날밤봄빛별꽃눈맘왜너누두좀못\
+둘싶했줄걸할볼올갈될길양평향현형호후결권근글급각건견남당록료류률름받번법변병복본불색석설\
+손순술승씨야약언업역열예왕외월응임작재절점접직질집차참책처청체초최추출충치친카크타태토트\
+특파판편포표품프피필항허활황효희";
+
+/// Returns a weight for how common a syllable is.
+///
+/// Unlisted syllables are penalized, so that readings with fewer, more common
+/// syllables are preferred.
+pub fn weight(c: char) -> i32 {
+    if COMMON.contains(c) {
+        1
+    } else if FREQUENT.contains(c) {
+        0
+    } else {
+        -2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_lists_are_disjoint() {
+        let mut seen = HashSet::new();
+
+        for c in COMMON.chars().chain(FREQUENT.chars()) {
+            assert!(seen.insert(c), "{} is listed more than once", c);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! Latin spellings of jamos, longest first within each group so that greedy
+//! readings are tried before shorter ones.
+
+use crate::{Choseong, Jongseong, Jungseong};
+
+pub static INITIAL_CONSONANTS: [(&str, Choseong); 19] = [
+    ("kk", Choseong::SsangGiyeok),
+    ("tt", Choseong::SsangDigeut),
+    ("pp", Choseong::SsangBieup),
+    ("ss", Choseong::SsangSiot),
+    ("jj", Choseong::SsangJieut),
+    ("ch", Choseong::Chieut),
+    ("g", Choseong::Giyeok),
+    ("n", Choseong::Nieun),
+    ("d", Choseong::Digeut),
+    ("r", Choseong::Rieul),
+    ("l", Choseong::Rieul),
+    ("m", Choseong::Mieum),
+    ("b", Choseong::Bieup),
+    ("s", Choseong::Siot),
+    ("j", Choseong::Jieut),
+    ("k", Choseong::Kieuk),
+    ("t", Choseong::Tieut),
+    ("p", Choseong::Pieup),
+    ("h", Choseong::Hieut),
+];
+
+/// McCune–Reischauer and informal spellings of initial consonants.
+pub static LOOSE_INITIAL_CONSONANTS: [(&str, Choseong); 10] = [
+    ("tch", Choseong::SsangJieut),
+    ("ch'", Choseong::Chieut),
+    ("k'", Choseong::Kieuk),
+    ("t'", Choseong::Tieut),
+    ("p'", Choseong::Pieup),
+    ("ch", Choseong::Jieut),
+    ("sh", Choseong::Siot),
+    ("k", Choseong::Giyeok),
+    ("t", Choseong::Digeut),
+    ("p", Choseong::Bieup),
+];
+
+pub static MEDIAL_VOWELS: [(&str, Jungseong); 21] = [
+    ("yae", Jungseong::Yae),
+    ("yeo", Jungseong::Yeo),
+    ("wae", Jungseong::Wae),
+    ("ae", Jungseong::Ae),
+    ("ya", Jungseong::Ya),
+    ("eo", Jungseong::Eo),
+    ("ye", Jungseong::Ye),
+    ("wa", Jungseong::Wa),
+    ("oe", Jungseong::Oe),
+    ("yo", Jungseong::Yo),
+    ("wo", Jungseong::Wo),
+    ("we", Jungseong::We),
+    ("wi", Jungseong::Wi),
+    ("yu", Jungseong::Yu),
+    ("eu", Jungseong::Eu),
+    ("ui", Jungseong::Ui),
+    ("a", Jungseong::A),
+    ("e", Jungseong::E),
+    ("o", Jungseong::O),
+    ("u", Jungseong::U),
+    ("i", Jungseong::I),
+];
+
+/// McCune–Reischauer and informal spellings of vowels.
+pub static LOOSE_MEDIAL_VOWELS: [(&str, Jungseong); 12] = [
+    ("yŏ", Jungseong::Yeo),
+    ("wŏ", Jungseong::Wo),
+    ("ŭi", Jungseong::Ui),
+    ("yo", Jungseong::Yeo),
+    ("oo", Jungseong::U),
+    ("ee", Jungseong::I),
+    ("ŏ", Jungseong::Eo),
+    ("ŭ", Jungseong::Eu),
+    ("o", Jungseong::Eo),
+    ("u", Jungseong::Eu),
+    ("u", Jungseong::Eo),
+    ("i", Jungseong::Ui),
+];
+
+/// Final consonants as they are pronounced.
+pub static FINAL_CONSONANTS: [(&str, Jongseong); 7] = [
+    ("ng", Jongseong::Ieung),
+    ("k", Jongseong::Giyeok),
+    ("n", Jongseong::Nieun),
+    ("t", Jongseong::Digeut),
+    ("l", Jongseong::Rieul),
+    ("m", Jongseong::Mieum),
+    ("p", Jongseong::Bieup),
+];
+
+/// Returns the spellings of a final consonant that are neutralized to `m`
+/// before a consonant or at the end of a word (standard pronunciation, §9).
+pub fn neutralized_final_consonants(m: Jongseong) -> &'static [Jongseong] {
+    match m {
+        Jongseong::Giyeok => &[Jongseong::Giyeok, Jongseong::SsangGiyeok, Jongseong::Kieuk],
+        Jongseong::Digeut => &[
+            Jongseong::Siot,
+            Jongseong::Digeut,
+            Jongseong::SsangSiot,
+            Jongseong::Jieut,
+            Jongseong::Chieut,
+            Jongseong::Tieut,
+        ],
+        Jongseong::Bieup => &[Jongseong::Bieup, Jongseong::Pieup],
+        Jongseong::Nieun => &[Jongseong::Nieun],
+        Jongseong::Rieul => &[Jongseong::Rieul],
+        Jongseong::Mieum => &[Jongseong::Mieum],
+        Jongseong::Ieung => &[Jongseong::Ieung],
+        _ => &[],
+    }
+}
@@ -0,0 +1,564 @@
+mod frequency;
+mod jamo;
+
+use std::collections::HashSet;
+
+use self::jamo::*;
+use crate::{Choseong, Jongseong, Jungseong, Pronouncer, Syllable, Word};
+
+/// The maximum number of ways to read an input before giving up.
+const MAX_READINGS: usize = 256;
+
+/// The maximum number of spellings checked per reading.
+const MAX_SPELLINGS: usize = 4096;
+
+/// A possible Hangeul spelling of a romanized word
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Candidate {
+    text: String,
+    score: i32,
+}
+
+impl Candidate {
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns how likely the candidate is. Higher is better.
+    pub fn score(&self) -> i32 {
+        self.score
+    }
+}
+
+/// Converts romanized text back to Hangeul.
+///
+/// Input is first read as a sequence of pronounced syllables, e.g.,
+/// "jongno" => 종노. Since romanization follows pronunciation, assimilations
+/// are then undone to find the spellings that are pronounced that way, e.g.,
+/// 종로 and 종노. Candidates are ranked by how common their syllables are.
+///
+/// By default, only Revised Romanization is read. When `loose`,
+/// McCune–Reischauer (ASCII or with breves) and some informal spellings are
+/// read as well.
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::Hangeulizer;
+///
+/// let hangeulizer = Hangeulizer::new();
+/// let candidates = hangeulizer.candidates("hanguk");
+///
+/// assert_eq!(candidates[0].as_str(), "한국");
+/// assert!(candidates.iter().any(|c| c.as_str() == "항욱"));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Hangeulizer {
+    loose: bool,
+    limit: usize,
+}
+
+impl Hangeulizer {
+    pub fn new() -> Hangeulizer {
+        Hangeulizer {
+            loose: false,
+            limit: 10,
+        }
+    }
+
+    /// Sets whether to also read McCune–Reischauer and informal spellings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hangeul::Hangeulizer;
+    ///
+    /// let hangeulizer = Hangeulizer::new().loose(true);
+    /// assert_eq!(hangeulizer.candidates("p'yŏngyang")[0].as_str(), "평양");
+    /// ```
+    pub fn loose(mut self, loose: bool) -> Hangeulizer {
+        self.loose = loose;
+        self
+    }
+
+    /// Sets the maximum number of candidates returned.
+    pub fn limit(mut self, limit: usize) -> Hangeulizer {
+        self.limit = limit;
+        self
+    }
+
+    /// Returns possible Hangeul spellings of a single romanized word, most
+    /// likely first.
+    ///
+    /// The result is empty if the word cannot be read as Korean.
+    pub fn candidates(&self, word: &str) -> Vec<Candidate> {
+        let word = word.to_lowercase();
+
+        let mut readings = Vec::new();
+        self.read(&word, None, &mut Vec::new(), &mut readings);
+
+        let pronouncer = Pronouncer::new(false);
+        let mut candidates: Vec<Candidate> = Vec::new();
+        let mut seen = HashSet::new();
+
+        for reading in readings {
+            for spelling in spellings(&reading) {
+                let text: String = spelling.iter().map(|s| s.as_char()).collect();
+
+                if !seen.insert(text.clone()) {
+                    continue;
+                }
+
+                let pronunciation = pronouncer.pronounce(&Word::from(spelling.clone()));
+
+                if pronunciation.syllables() == reading {
+                    let score = score(&spelling, &reading);
+                    candidates.push(Candidate { text, score });
+                }
+            }
+        }
+
+        candidates.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.text.cmp(&b.text)));
+        candidates.truncate(self.limit);
+
+        candidates
+    }
+
+    /// Replaces each romanized word in the text with its most likely
+    /// Hangeul spelling.
+    ///
+    /// Words that cannot be read as Korean are left as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hangeul::Hangeulizer;
+    ///
+    /// let hangeulizer = Hangeulizer::new();
+    /// assert_eq!(hangeulizer.hangeulize("saranghae (2x)"), "사랑해 (2x)");
+    /// ```
+    pub fn hangeulize(&self, text: &str) -> String {
+        let mut res = String::new();
+        let mut word = String::new();
+
+        for c in text.chars() {
+            if self.is_word_char(c) {
+                word.push(c);
+            } else {
+                self.flush(&mut word, &mut res);
+                res.push(c);
+            }
+        }
+
+        self.flush(&mut word, &mut res);
+
+        res
+    }
+
+    /// Writes the most likely spelling of a pending word, if any, to `res`.
+    fn flush(&self, word: &mut String, res: &mut String) {
+        if word.is_empty() {
+            return;
+        }
+
+        match self.candidates(word).first() {
+            Some(candidate) => res.push_str(candidate.as_str()),
+            None => res.push_str(word),
+        }
+
+        word.clear();
+    }
+
+    fn is_word_char(&self, c: char) -> bool {
+        c.is_ascii_alphabetic() || (self.loose && (c == 'ŏ' || c == 'ŭ' || c == '\''))
+    }
+
+    /// Finds all ways to read the input as pronounced syllables.
+    fn read(
+        &self,
+        s: &str,
+        prev: Option<Option<Jongseong>>,
+        acc: &mut Vec<Syllable>,
+        readings: &mut Vec<Vec<Syllable>>,
+    ) {
+        if readings.len() >= MAX_READINGS {
+            return;
+        }
+
+        if s.is_empty() {
+            if !acc.is_empty() {
+                readings.push(acc.clone());
+            }
+
+            return;
+        }
+
+        let loose_initial_consonants: &[(&str, Choseong)] = if self.loose {
+            &LOOSE_INITIAL_CONSONANTS
+        } else {
+            &[]
+        };
+
+        let loose_medial_vowels: &[(&str, Jungseong)] = if self.loose {
+            &LOOSE_MEDIAL_VOWELS
+        } else {
+            &[]
+        };
+
+        let initial_consonants = INITIAL_CONSONANTS
+            .iter()
+            .chain(loose_initial_consonants)
+            .chain(&[("", Choseong::Ieung)]);
+
+        for &(a, j) in initial_consonants {
+            // An initial ㄹ is written l only after a final ㄹ.
+            if !self.loose
+                && j == Choseong::Rieul
+                && (a == "l") != (prev == Some(Some(Jongseong::Rieul)))
+            {
+                continue;
+            }
+
+            let rest = match s.strip_prefix(a) {
+                Some(rest) => rest,
+                None => continue,
+            };
+
+            for &(b, k) in MEDIAL_VOWELS.iter().chain(loose_medial_vowels) {
+                let rest = match rest.strip_prefix(b) {
+                    Some(rest) => rest,
+                    None => continue,
+                };
+
+                acc.push(Syllable::new(j, k, None));
+                self.read(rest, Some(None), acc, readings);
+                acc.pop();
+
+                for &(c, m) in FINAL_CONSONANTS.iter() {
+                    if let Some(rest) = rest.strip_prefix(c) {
+                        acc.push(Syllable::new(j, k, Some(m)));
+                        self.read(rest, Some(Some(m)), acc, readings);
+                        acc.pop();
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Default for Hangeulizer {
+    fn default() -> Hangeulizer {
+        Hangeulizer::new()
+    }
+}
+
+/// Replaces each romanized word in the text with its most likely Hangeul
+/// spelling, reading Revised Romanization.
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::hangeulize;
+///
+/// assert_eq!(hangeulize("annyeonghaseyo"), "안녕하세요");
+/// assert_eq!(hangeulize("jongno"), "종로");
+/// ```
+pub fn hangeulize(text: &str) -> String {
+    Hangeulizer::new().hangeulize(text)
+}
+
+/// Scores a spelling by how common its syllables are.
+///
+/// Syllables starting with a vowel are penalized since other readings are
+/// usually more likely: after a vowel, it is more likely part of a diphthong
+/// ("hae" is 해 rather than 하에), and after a final consonant, the consonant
+/// is more likely the initial ("hanguk" is 한국 rather than 항욱).
+///
+/// An initial ㄹ that is pronounced ㄴ (§19) is also penalized. It is rare
+/// outside of Sino-Korean compounds (종로), but otherwise wins over common
+/// endings ("gamsahamnida" is 감사합니다 rather than 감사합리다).
+fn score(spelling: &[Syllable], reading: &[Syllable]) -> i32 {
+    let penalty: i32 = spelling
+        .windows(2)
+        .filter(|w| w[1].choseong() == Choseong::Ieung)
+        .map(|w| if w[0].jongseong().is_none() { 2 } else { 1 })
+        .sum();
+
+    let penalty = penalty
+        + spelling
+            .iter()
+            .zip(reading)
+            .filter(|(s, r)| s.choseong() == Choseong::Rieul && r.choseong() == Choseong::Nieun)
+            .count() as i32
+            * 2;
+
+    spelling
+        .iter()
+        .map(|s| frequency::weight(s.as_char()))
+        .sum::<i32>()
+        - penalty
+}
+
+/// Returns the spellings that could be pronounced as the given syllables.
+///
+/// Each syllable boundary is undone independently, so the result includes
+/// spellings that are not actually pronounced that way. These are filtered
+/// by the caller.
+fn spellings(reading: &[Syllable]) -> Vec<Vec<Syllable>> {
+    let mut options = Vec::with_capacity(reading.len());
+
+    for (s, t) in reading.iter().zip(reading.iter().skip(1)) {
+        options.push(boundary_options(s.jongseong(), t.choseong(), t.jungseong()));
+    }
+
+    let last = reading[reading.len() - 1].jongseong();
+    let last_options: Vec<Option<Jongseong>> = match last {
+        Some(m) => neutralized_final_consonants(m)
+            .iter()
+            .map(|&n| Some(n))
+            .collect(),
+        None => vec![None],
+    };
+
+    let mut res = Vec::new();
+    let mut indices = vec![0; options.len()];
+
+    'outer: loop {
+        for &last in &last_options {
+            let mut spelling = reading.to_vec();
+
+            for (i, &k) in indices.iter().enumerate() {
+                let (m, j) = options[i][k];
+                spelling[i].set_jongseong(m);
+                spelling[i + 1].set_choseong(j);
+            }
+
+            let n = spelling.len() - 1;
+            spelling[n].set_jongseong(last);
+
+            res.push(spelling);
+
+            if res.len() >= MAX_SPELLINGS {
+                break 'outer;
+            }
+        }
+
+        // Advances to the next combination of boundary options.
+        for i in (0..indices.len()).rev() {
+            indices[i] += 1;
+
+            if indices[i] < options[i].len() {
+                continue 'outer;
+            }
+
+            indices[i] = 0;
+        }
+
+        break;
+    }
+
+    res
+}
+
+/// Returns the final and initial consonants that could be pronounced as the
+/// final consonant `m` followed by the syllable `j` + `k`.
+fn boundary_options(
+    m: Option<Jongseong>,
+    j: Choseong,
+    k: Jungseong,
+) -> Vec<(Option<Jongseong>, Choseong)> {
+    let mut res = Vec::new();
+
+    match m {
+        Some(m) if j != Choseong::Ieung => {
+            for &n in neutralized_final_consonants(m) {
+                res.push((Some(n), j));
+            }
+        }
+        _ => res.push((m, j)),
+    }
+
+    match (m, j) {
+        (None, Choseong::Ieung) => {}
+        (None, _) => {
+            // linking (§13), except for ㄸ, ㅃ, and ㅉ, which cannot be final
+            if let Some(n) = j.to_jongseong() {
+                res.push((Some(n), Choseong::Ieung));
+            }
+
+            // palatalization (§17)
+            if k == Jungseong::I {
+                match j {
+                    Choseong::Jieut => res.push((Some(Jongseong::Digeut), Choseong::Ieung)),
+                    Choseong::Chieut => {
+                        res.push((Some(Jongseong::Tieut), Choseong::Ieung));
+                        res.push((Some(Jongseong::Digeut), Choseong::Hieut));
+                    }
+                    _ => {}
+                }
+            }
+
+            // aspiration (§12.1)
+            if let Some(plain) = unaspirate(j) {
+                res.push((Some(Jongseong::Hieut), plain));
+            }
+        }
+        // linking of consonant clusters (§14)
+        (Some(m), _) => {
+            let cluster = match (m, j) {
+                (Jongseong::Giyeok, Choseong::Siot) => Some(Jongseong::GiyeokSiot),
+                (Jongseong::Nieun, Choseong::Jieut) => Some(Jongseong::NieunJieut),
+                (Jongseong::Rieul, Choseong::Giyeok) => Some(Jongseong::RieulGiyeok),
+                (Jongseong::Rieul, Choseong::Mieum) => Some(Jongseong::RieulMieum),
+                (Jongseong::Rieul, Choseong::Bieup) => Some(Jongseong::RieulBieup),
+                (Jongseong::Rieul, Choseong::Siot) => Some(Jongseong::RieulSiot),
+                (Jongseong::Rieul, Choseong::Tieut) => Some(Jongseong::RieulTieut),
+                (Jongseong::Rieul, Choseong::Pieup) => Some(Jongseong::RieulPieup),
+                (Jongseong::Bieup, Choseong::Siot) => Some(Jongseong::BieupSiot),
+                _ => None,
+            };
+
+            if let Some(cluster) = cluster {
+                res.push((Some(cluster), Choseong::Ieung));
+            }
+
+            // aspiration (§12.1)
+            if let Some(plain) = unaspirate(j) {
+                match m {
+                    Jongseong::Nieun => res.push((Some(Jongseong::NieunHieut), plain)),
+                    Jongseong::Rieul => res.push((Some(Jongseong::RieulHieut), plain)),
+                    _ => {}
+                }
+            }
+
+            // nasalization (§18) and ㄹ to ㄴ (§19)
+            match (m, j) {
+                (Jongseong::Ieung, Choseong::Nieun) | (Jongseong::Ieung, Choseong::Mieum) => {
+                    res.push((Some(Jongseong::Giyeok), j));
+                    res.push((Some(Jongseong::SsangGiyeok), j));
+
+                    if j == Choseong::Nieun {
+                        res.push((Some(Jongseong::Ieung), Choseong::Rieul));
+                        res.push((Some(Jongseong::Giyeok), Choseong::Rieul));
+                    }
+                }
+                (Jongseong::Nieun, Choseong::Nieun) | (Jongseong::Nieun, Choseong::Mieum) => {
+                    for &n in &[
+                        Jongseong::Digeut,
+                        Jongseong::Siot,
+                        Jongseong::SsangSiot,
+                        Jongseong::Jieut,
+                        Jongseong::Chieut,
+                        Jongseong::Tieut,
+                        Jongseong::Hieut,
+                    ] {
+                        res.push((Some(n), j));
+                    }
+                }
+                (Jongseong::Mieum, Choseong::Nieun) | (Jongseong::Mieum, Choseong::Mieum) => {
+                    res.push((Some(Jongseong::Bieup), j));
+                    res.push((Some(Jongseong::Pieup), j));
+
+                    if j == Choseong::Nieun {
+                        res.push((Some(Jongseong::Mieum), Choseong::Rieul));
+                        res.push((Some(Jongseong::Bieup), Choseong::Rieul));
+                    }
+                }
+                // lateralization (§20)
+                (Jongseong::Rieul, Choseong::Rieul) => {
+                    res.push((Some(Jongseong::Nieun), Choseong::Rieul));
+                    res.push((Some(Jongseong::Rieul), Choseong::Nieun));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    res
+}
+
+fn unaspirate(j: Choseong) -> Option<Choseong> {
+    match j {
+        Choseong::Kieuk => Some(Choseong::Giyeok),
+        Choseong::Tieut => Some(Choseong::Digeut),
+        Choseong::Chieut => Some(Choseong::Jieut),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn c(s: &str) -> Vec<String> {
+        Hangeulizer::new()
+            .limit(usize::MAX)
+            .candidates(s)
+            .into_iter()
+            .map(|c| c.text)
+            .collect()
+    }
+
+    #[test]
+    fn test_candidates() {
+        assert_eq!(c("saranghae")[0], "사랑해");
+        assert_eq!(c("annyeonghaseyo")[0], "안녕하세요");
+
+        let candidates = c("hanguk");
+        assert_eq!(candidates[0], "한국");
+        assert!(candidates.contains(&String::from("항욱")));
+
+        let candidates = c("gamsahamnida");
+        assert_eq!(candidates[0], "감사합니다");
+        assert!(candidates.contains(&String::from("감사합리다")));
+
+        let candidates = c("annyeonghasimnikka");
+        assert_eq!(candidates[0], "안녕하십니까");
+        assert!(candidates.contains(&String::from("안녕하심리까")));
+    }
+
+    #[test]
+    fn test_candidates_with_assimilation() {
+        let candidates = c("jongno");
+        assert_eq!(candidates[0], "종로");
+        assert!(candidates.contains(&String::from("종노")));
+
+        assert!(c("hangugeo").contains(&String::from("한국어")));
+        assert!(c("gungmul").contains(&String::from("국물")));
+        assert!(c("silla").contains(&String::from("신라")));
+        assert!(c("joko").contains(&String::from("좋고")));
+        assert!(c("guji").contains(&String::from("굳이")));
+        assert!(c("eopseo").contains(&String::from("없어")));
+        assert!(c("otgoreum").contains(&String::from("옷고름")));
+    }
+
+    #[test]
+    fn test_candidates_are_pronounced_as_input() {
+        for candidate in c("hangugeo") {
+            assert_eq!(Word::new(candidate).unwrap().romanize(), "hangugeo");
+        }
+    }
+
+    #[test]
+    fn test_candidates_with_invalid_input() {
+        assert!(c("xyz").is_empty());
+        assert!(c("").is_empty());
+    }
+
+    #[test]
+    fn test_hangeulize() {
+        let hangeulizer = Hangeulizer::new();
+        assert_eq!(hangeulizer.hangeulize("a\0ga"), "아\0가");
+        assert_eq!(hangeulizer.hangeulize("\0"), "\0");
+        assert_eq!(hangeulizer.hangeulize("jongno\0"), "종로\0");
+    }
+
+    #[test]
+    fn test_loose() {
+        let hangeulizer = Hangeulizer::new().loose(true);
+        assert_eq!(hangeulizer.hangeulize("p'yŏngyang"), "평양");
+        assert_eq!(hangeulizer.hangeulize("pusan"), "부산");
+
+        assert!(Hangeulizer::new().candidates("ŏ").is_empty());
+    }
+}
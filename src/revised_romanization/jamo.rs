@@ -1,84 +1,83 @@
-pub fn transliterate_initial_consonant(j: char) -> &'static str {
+use crate::{Choseong, Jongseong, Jungseong};
+
+pub fn transliterate_initial_consonant(j: Choseong) -> &'static str {
     match j {
-        'ㄱ' => "g",
-        'ㄲ' => "kk",
-        'ㄴ' => "n",
-        'ㄷ' => "d",
-        'ㄸ' => "tt",
-        'ㄹ' => "r",
-        'ㅁ' => "m",
-        'ㅂ' => "b",
-        'ㅃ' => "pp",
-        'ㅅ' => "s",
-        'ㅆ' => "ss",
-        'ㅇ' => "",
-        'ㅈ' => "j",
-        'ㅉ' => "jj",
-        'ㅊ' => "ch",
-        'ㅋ' => "k",
-        'ㅌ' => "t",
-        'ㅍ' => "p",
-        'ㅎ' => "h",
-        _ => unreachable!(),
+        Choseong::Giyeok => "g",
+        Choseong::SsangGiyeok => "kk",
+        Choseong::Nieun => "n",
+        Choseong::Digeut => "d",
+        Choseong::SsangDigeut => "tt",
+        Choseong::Rieul => "r",
+        Choseong::Mieum => "m",
+        Choseong::Bieup => "b",
+        Choseong::SsangBieup => "pp",
+        Choseong::Siot => "s",
+        Choseong::SsangSiot => "ss",
+        Choseong::Ieung => "",
+        Choseong::Jieut => "j",
+        Choseong::SsangJieut => "jj",
+        Choseong::Chieut => "ch",
+        Choseong::Kieuk => "k",
+        Choseong::Tieut => "t",
+        Choseong::Pieup => "p",
+        Choseong::Hieut => "h",
     }
 }
 
-pub fn transliterate_medial_vowel(j: char) -> &'static str {
+pub fn transliterate_medial_vowel(j: Jungseong) -> &'static str {
     match j {
-        'ㅏ' => "a",
-        'ㅐ' => "ae",
-        'ㅑ' => "ya",
-        'ㅒ' => "yae",
-        'ㅓ' => "eo",
-        'ㅔ' => "e",
-        'ㅕ' => "yeo",
-        'ㅖ' => "ye",
-        'ㅗ' => "o",
-        'ㅘ' => "wa",
-        'ㅙ' => "wae",
-        'ㅚ' => "oe",
-        'ㅛ' => "yo",
-        'ㅜ' => "u",
-        'ㅝ' => "wo",
-        'ㅞ' => "we",
-        'ㅟ' => "wi",
-        'ㅠ' => "yu",
-        'ㅡ' => "eu",
-        'ㅢ' => "ui",
-        'ㅣ' => "i",
-        _ => unreachable!(),
+        Jungseong::A => "a",
+        Jungseong::Ae => "ae",
+        Jungseong::Ya => "ya",
+        Jungseong::Yae => "yae",
+        Jungseong::Eo => "eo",
+        Jungseong::E => "e",
+        Jungseong::Yeo => "yeo",
+        Jungseong::Ye => "ye",
+        Jungseong::O => "o",
+        Jungseong::Wa => "wa",
+        Jungseong::Wae => "wae",
+        Jungseong::Oe => "oe",
+        Jungseong::Yo => "yo",
+        Jungseong::U => "u",
+        Jungseong::Wo => "wo",
+        Jungseong::We => "we",
+        Jungseong::Wi => "wi",
+        Jungseong::Yu => "yu",
+        Jungseong::Eu => "eu",
+        Jungseong::Ui => "ui",
+        Jungseong::I => "i",
     }
 }
 
-pub fn transliterate_final_consonant(j: char) -> &'static str {
+pub fn transliterate_final_consonant(j: Jongseong) -> &'static str {
     match j {
-        'ㄱ' => "k",
-        'ㄲ' => "k",
-        'ㄳ' => "[?]",
-        'ㄴ' => "n",
-        'ㄵ' => "[?]",
-        'ㄶ' => "[?]",
-        'ㄷ' => "t",
-        'ㄹ' => "l",
-        'ㄺ' => "[?]",
-        'ㄻ' => "[?]",
-        'ㄼ' => "[?]",
-        'ㄽ' => "[?]",
-        'ㄾ' => "[?]",
-        'ㄿ' => "[?]",
-        'ㅀ' => "[?]",
-        'ㅁ' => "m",
-        'ㅂ' => "p",
-        'ㅄ' => "[?]",
-        'ㅅ' => "t",
-        'ㅆ' => "t",
-        'ㅇ' => "ng",
-        'ㅈ' => "t",
-        'ㅊ' => "t",
-        'ㅋ' => "k",
-        'ㅌ' => "t",
-        'ㅍ' => "p",
-        'ㅎ' => "h",
-        _ => unreachable!(),
+        Jongseong::Giyeok => "k",
+        Jongseong::SsangGiyeok => "k",
+        Jongseong::GiyeokSiot => "[?]",
+        Jongseong::Nieun => "n",
+        Jongseong::NieunJieut => "[?]",
+        Jongseong::NieunHieut => "[?]",
+        Jongseong::Digeut => "t",
+        Jongseong::Rieul => "l",
+        Jongseong::RieulGiyeok => "[?]",
+        Jongseong::RieulMieum => "[?]",
+        Jongseong::RieulBieup => "[?]",
+        Jongseong::RieulSiot => "[?]",
+        Jongseong::RieulTieut => "[?]",
+        Jongseong::RieulPieup => "[?]",
+        Jongseong::RieulHieut => "[?]",
+        Jongseong::Mieum => "m",
+        Jongseong::Bieup => "p",
+        Jongseong::BieupSiot => "[?]",
+        Jongseong::Siot => "t",
+        Jongseong::SsangSiot => "t",
+        Jongseong::Ieung => "ng",
+        Jongseong::Jieut => "t",
+        Jongseong::Chieut => "t",
+        Jongseong::Kieuk => "k",
+        Jongseong::Tieut => "t",
+        Jongseong::Pieup => "p",
+        Jongseong::Hieut => "h",
     }
 }
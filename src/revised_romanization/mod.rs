@@ -1,40 +1,56 @@
-mod jamo;
+pub(crate) mod jamo;
 
 use self::jamo::*;
-use crate::Word;
-
-pub fn transcribe(word: &Word) -> String {
-    let syllables = word.syllables();
-    let mut it = syllables.iter().peekable();
-    let mut res = String::new();
-
-    let mut skip = false;
-
-    while let Some(s) = it.next() {
-        let (j, k, m) = s.decompose();
-
-        if !skip {
-            res.push_str(transliterate_initial_consonant(j));
-        } else {
-            skip = false;
-        }
+use crate::{Choseong, Context, Jongseong, Syllable, Transcriber};
+
+/// Revised Romanization of Korean
+///
+/// This is the official romanization system of South Korea and the default
+/// used by [`romanize`](crate::romanize).
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::{romanize_with, RevisedRomanization};
+///
+/// assert_eq!(romanize_with("신라", &RevisedRomanization), "silla");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct RevisedRomanization;
+
+impl Transcriber for RevisedRomanization {
+    fn transcribe(&self, syllables: &[Syllable], _: &Context) -> String {
+        let mut it = syllables.iter().peekable();
+        let mut res = String::new();
+
+        let mut skip = false;
+
+        while let Some(s) = it.next() {
+            let (j, k, m) = s.decompose();
+
+            if !skip {
+                res.push_str(transliterate_initial_consonant(j));
+            } else {
+                skip = false;
+            }
 
-        res.push_str(transliterate_medial_vowel(k));
+            res.push_str(transliterate_medial_vowel(k));
 
-        if let Some(m) = m {
-            let t = it.peek();
+            if let Some(m) = m {
+                let t = it.peek();
 
-            if let Some(n) = t.map(|t| t.choseong()) {
-                if m == 'ㄹ' && n == 'ㄹ' {
-                    res.push_str("ll");
-                    skip = true;
-                    continue;
+                if let Some(n) = t.map(|t| t.choseong()) {
+                    if m == Jongseong::Rieul && n == Choseong::Rieul {
+                        res.push_str("ll");
+                        skip = true;
+                        continue;
+                    }
                 }
-            }
 
-            res.push_str(transliterate_final_consonant(m));
+                res.push_str(transliterate_final_consonant(m));
+            }
         }
-    }
 
-    res
+        res
+    }
 }
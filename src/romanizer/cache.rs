@@ -0,0 +1,219 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+
+/// Hit and miss statistics of a romanizer cache
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::Romanizer;
+///
+/// let romanizer = Romanizer::default().cache(1024);
+/// romanizer.romanize("우리 우리");
+///
+/// let stats = romanizer.cache_stats().unwrap();
+/// assert_eq!(stats.hits(), 1);
+/// assert_eq!(stats.misses(), 1);
+/// assert_eq!(stats.len(), 1);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CacheStats {
+    hits: u64,
+    misses: u64,
+    len: usize,
+    capacity: usize,
+}
+
+impl CacheStats {
+    /// Returns the number of words that were found in the cache.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Returns the number of words that were not found in the cache.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Returns the ratio of hits to lookups, or 0 when there were none.
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+
+    /// Returns the number of words in the cache.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the maximum number of words in the cache.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+#[derive(Debug)]
+struct Entry {
+    romanization: String,
+    tick: u64,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    entries: HashMap<String, Entry>,
+    // The keys of `entries` by when they were last used.
+    ticks: BTreeMap<u64, String>,
+    tick: u64,
+    hits: u64,
+    misses: u64,
+}
+
+/// A bounded least recently used cache of word romanizations
+#[derive(Debug)]
+pub(crate) struct Cache {
+    inner: Mutex<Inner>,
+    capacity: usize,
+}
+
+impl Cache {
+    pub fn new(capacity: usize) -> Cache {
+        Cache {
+            inner: Mutex::new(Inner::default()),
+            capacity,
+        }
+    }
+
+    /// Returns the romanization of a word, marking it as recently used.
+    pub fn get(&self, word: &str) -> Option<String> {
+        let mut inner = self.lock();
+        inner.tick += 1;
+
+        let Inner {
+            entries,
+            ticks,
+            tick,
+            hits,
+            misses,
+        } = &mut *inner;
+
+        match entries.get_mut(word) {
+            Some(entry) => {
+                let key = ticks.remove(&entry.tick).unwrap_or_default();
+                ticks.insert(*tick, key);
+                entry.tick = *tick;
+                *hits += 1;
+                Some(entry.romanization.clone())
+            }
+            None => {
+                *misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Adds the romanization of a word, evicting the least recently used
+    /// word when the cache is full.
+    pub fn insert(&self, word: &str, romanization: String) {
+        let mut inner = self.lock();
+
+        if inner.entries.contains_key(word) {
+            return;
+        }
+
+        if inner.entries.len() >= self.capacity {
+            if let Some((_, key)) = inner.ticks.pop_first() {
+                inner.entries.remove(&key);
+            }
+        }
+
+        inner.tick += 1;
+        let tick = inner.tick;
+
+        inner.ticks.insert(tick, word.into());
+        inner
+            .entries
+            .insert(word.into(), Entry { romanization, tick });
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        let inner = self.lock();
+
+        CacheStats {
+            hits: inner.hits,
+            misses: inner.misses,
+            len: inner.entries.len(),
+            capacity: self.capacity,
+        }
+    }
+
+    pub fn clear(&self) {
+        *self.lock() = Inner::default();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        // The cache is left consistent between statements, so a panic while
+        // it is locked does not invalidate it.
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get() {
+        let cache = Cache::new(2);
+        assert_eq!(cache.get("신라"), None);
+
+        cache.insert("신라", String::from("silla"));
+        assert_eq!(cache.get("신라"), Some(String::from("silla")));
+
+        let stats = cache.stats();
+        assert_eq!(stats.hits(), 1);
+        assert_eq!(stats.misses(), 1);
+        assert_eq!(stats.hit_rate(), 0.5);
+    }
+
+    #[test]
+    fn test_insert_evicts_least_recently_used() {
+        let cache = Cache::new(2);
+
+        cache.insert("신라", String::from("silla"));
+        cache.insert("종로", String::from("jongno"));
+        cache.get("신라");
+        cache.insert("국물", String::from("gungmul"));
+
+        assert!(cache.get("신라").is_some());
+        assert!(cache.get("종로").is_none());
+        assert!(cache.get("국물").is_some());
+        assert_eq!(cache.stats().len(), 2);
+    }
+
+    #[test]
+    fn test_clear() {
+        let cache = Cache::new(2);
+        cache.insert("신라", String::from("silla"));
+        cache.get("신라");
+        cache.clear();
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                capacity: 2,
+                ..CacheStats::default()
+            }
+        );
+    }
+}
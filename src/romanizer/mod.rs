@@ -0,0 +1,309 @@
+mod cache;
+
+pub use self::cache::CacheStats;
+
+use std::{num::NonZeroUsize, panic, sync::Arc, thread};
+
+use self::cache::Cache;
+use crate::{lexer, romanize_with, RevisedRomanization, Transcriber, Word};
+
+/// The number of inputs each thread romanizes per batch in
+/// [`Romanizer::romanize_iter`].
+const CHUNK_LEN: usize = 1024;
+
+/// A shared romanization configuration for batches of text
+///
+/// A romanizer is immutable, so one can be shared by any number of threads.
+/// Batches are split across threads, and the output is always in input
+/// order.
+///
+/// A romanizer can optionally cache the romanizations of recently used
+/// words, skipping the pronunciation rules and transcription for common
+/// words. The cache belongs to the romanizer and its clones, so entries are
+/// always for the same transcriber and options.
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::{Romanizer, Yale};
+///
+/// let romanizer = Romanizer::new(Yale::new());
+/// assert_eq!(
+///     romanizer.romanize_batch(&["한국어", "서울"]),
+///     ["hankwuk.e", "sewul"],
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Romanizer<T = RevisedRomanization> {
+    transcriber: T,
+    threads: usize,
+    cache: Option<Arc<Cache>>,
+}
+
+impl<T> Romanizer<T>
+where
+    T: Transcriber + Sync,
+{
+    /// Creates a romanizer using the given transcriber and as many threads
+    /// as are available.
+    pub fn new(transcriber: T) -> Romanizer<T> {
+        let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+
+        Romanizer {
+            transcriber,
+            threads,
+            cache: None,
+        }
+    }
+
+    /// Sets the maximum number of threads used per batch.
+    ///
+    /// With 0 or 1, batches are romanized on the calling thread.
+    pub fn threads(mut self, threads: usize) -> Romanizer<T> {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// Caches the romanizations of up to `capacity` words, evicting the
+    /// least recently used.
+    ///
+    /// With 0, nothing is cached. The cache is shared by clones of the
+    /// romanizer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hangeul::Romanizer;
+    ///
+    /// let romanizer = Romanizer::default().cache(1024);
+    /// assert_eq!(romanizer.romanize("종로 종로"), "jongno jongno");
+    /// assert_eq!(romanizer.cache_stats().unwrap().hits(), 1);
+    /// ```
+    pub fn cache(mut self, capacity: usize) -> Romanizer<T> {
+        self.cache = if capacity == 0 {
+            None
+        } else {
+            Some(Arc::new(Cache::new(capacity)))
+        };
+
+        self
+    }
+
+    /// Returns the statistics of the cache, if caching is enabled.
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.cache.as_ref().map(|cache| cache.stats())
+    }
+
+    /// Removes all words from the cache and resets its statistics.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.clear();
+        }
+    }
+
+    /// Returns the transcriber.
+    pub fn transcriber(&self) -> &T {
+        &self.transcriber
+    }
+
+    /// Romanizes text.
+    pub fn romanize(&self, input: &str) -> String {
+        let cache = match &self.cache {
+            Some(cache) => cache,
+            None => return romanize_with(input, &self.transcriber),
+        };
+
+        let mut s = String::with_capacity(input.len());
+
+        for (is_word, run) in lexer::runs(input) {
+            if !is_word {
+                s.push_str(run);
+                continue;
+            }
+
+            match cache.get(run) {
+                Some(romanization) => s.push_str(&romanization),
+                None => {
+                    let romanization = Word::new(run)
+                        .map_or_else(|_| run.to_string(), |w| w.romanize_with(&self.transcriber));
+                    s.push_str(&romanization);
+                    cache.insert(run, romanization);
+                }
+            }
+        }
+
+        s
+    }
+
+    /// Romanizes a batch of text in parallel.
+    ///
+    /// The output is in the same order as the input.
+    pub fn romanize_batch<S>(&self, inputs: &[S]) -> Vec<String>
+    where
+        S: AsRef<str> + Sync,
+    {
+        let threads = self.threads.min(inputs.len());
+
+        if threads <= 1 {
+            return inputs.iter().map(|s| self.romanize(s.as_ref())).collect();
+        }
+
+        let chunk_len = inputs.len().div_ceil(threads);
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = inputs
+                .chunks(chunk_len)
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        chunk
+                            .iter()
+                            .map(|s| self.romanize(s.as_ref()))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+                .collect()
+        })
+    }
+
+    /// Romanizes text from an iterator in parallel batches.
+    ///
+    /// Inputs are read lazily, a batch at a time, so the iterator can be
+    /// arbitrarily long. The output is in the same order as the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hangeul::Romanizer;
+    ///
+    /// let romanizer = Romanizer::default();
+    /// let lines = "신라\n종로\n".lines();
+    /// let output: Vec<_> = romanizer.romanize_iter(lines).collect();
+    /// assert_eq!(output, ["silla", "jongno"]);
+    /// ```
+    pub fn romanize_iter<'a, I>(&'a self, inputs: I) -> impl Iterator<Item = String> + 'a
+    where
+        I: IntoIterator,
+        I::IntoIter: 'a,
+        I::Item: AsRef<str> + Sync,
+    {
+        let mut inputs = inputs.into_iter();
+        let mut outputs = Vec::new().into_iter();
+        let batch_len = self.threads * CHUNK_LEN;
+
+        std::iter::from_fn(move || loop {
+            if let Some(output) = outputs.next() {
+                return Some(output);
+            }
+
+            let batch: Vec<_> = inputs.by_ref().take(batch_len).collect();
+
+            if batch.is_empty() {
+                return None;
+            }
+
+            outputs = self.romanize_batch(&batch).into_iter();
+        })
+    }
+}
+
+impl Default for Romanizer<RevisedRomanization> {
+    fn default() -> Romanizer<RevisedRomanization> {
+        Romanizer::new(RevisedRomanization)
+    }
+}
+
+/// Romanizes a batch of text in parallel using Revised Romanization rules.
+///
+/// See [`Romanizer::romanize_batch`].
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::romanize_batch;
+///
+/// assert_eq!(romanize_batch(&["신라", "종로"]), ["silla", "jongno"]);
+/// ```
+pub fn romanize_batch(inputs: &[&str]) -> Vec<String> {
+    Romanizer::default().romanize_batch(inputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Romanizer;
+    use crate::*;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_send_sync() {
+        assert_send_sync::<Dprk>();
+        assert_send_sync::<Ipa>();
+        assert_send_sync::<Iso11941>();
+        assert_send_sync::<McCuneReischauer>();
+        assert_send_sync::<PhoneSet>();
+        assert_send_sync::<Popular>();
+        assert_send_sync::<Pronouncer>();
+        assert_send_sync::<RevisedRomanization>();
+        assert_send_sync::<CacheStats>();
+        assert_send_sync::<Romanizer<Scheme>>();
+        assert_send_sync::<Romanizer>();
+        assert_send_sync::<Scheme>();
+        assert_send_sync::<SearchIndex>();
+        assert_send_sync::<Hangeulizer>();
+        assert_send_sync::<Word>();
+        assert_send_sync::<Yale>();
+    }
+
+    #[test]
+    fn test_romanize_batch() {
+        let inputs: Vec<String> = (0..1000)
+            .map(|i| format!("{} 한국어 {}", i, ["신라", "종로", "없어요"][i % 3]))
+            .collect();
+
+        let expected: Vec<_> = inputs.iter().map(|s| romanize(s)).collect();
+
+        for &threads in &[0, 1, 3, 16, 2000] {
+            let romanizer = Romanizer::default().threads(threads);
+            assert_eq!(romanizer.romanize_batch(&inputs), expected);
+        }
+
+        let romanizer = Romanizer::default();
+        assert!(romanizer.romanize_batch::<&str>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_romanize_batch_with_cache() {
+        let inputs: Vec<_> = (0..1000).map(|i| ["신라", "종로 신라"][i % 2]).collect();
+        let romanizer = Romanizer::default().threads(4).cache(16);
+        let outputs = romanizer.romanize_batch(&inputs);
+
+        assert!(outputs
+            .chunks(2)
+            .all(|pair| pair == ["silla", "jongno silla"]));
+
+        let stats = romanizer.cache_stats().unwrap();
+        assert_eq!(stats.hits() + stats.misses(), 1500);
+        assert!(stats.misses() >= 2);
+        assert_eq!(stats.len(), 2);
+
+        romanizer.clear_cache();
+        assert!(romanizer.cache_stats().unwrap().is_empty());
+
+        assert!(Romanizer::default().cache(0).cache_stats().is_none());
+    }
+
+    #[test]
+    fn test_romanize_iter() {
+        let romanizer = Romanizer::new(Popular::new()).threads(4);
+        let inputs = (0..10_000).map(|i| if i % 2 == 0 { "우주" } else { "이" });
+        let outputs: Vec<_> = romanizer.romanize_iter(inputs).collect();
+
+        assert_eq!(outputs.len(), 10_000);
+        assert!(outputs.chunks(2).all(|pair| pair == ["woojoo", "lee"]));
+    }
+}
@@ -0,0 +1,163 @@
+use crate::ssml::push_escaped;
+use crate::{romanize_aligned_with, Lexer, Pronouncer, RevisedRomanization, Token, Transcriber};
+
+/// An HTML ruby annotation writer
+///
+/// Each word is wrapped in a `ruby` element with its romanization in an
+/// `rt` element, either for the whole word or for each syllable. All other
+/// text is escaped and passed through.
+///
+/// Optionally, the standard pronunciation of each word is added as a second
+/// tier, by nesting the `ruby` element in another.
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::Ruby;
+///
+/// let ruby = Ruby::new();
+/// assert_eq!(
+///     ruby.render("한국어 & 영어"),
+///     "<ruby>한국어<rt>hangugeo</rt></ruby> &amp; <ruby>영어<rt>yeongeo</rt></ruby>",
+/// );
+///
+/// let ruby = Ruby::new().per_syllable(true);
+/// assert_eq!(
+///     ruby.render("신라"),
+///     "<ruby>신<rt>sil</rt>라<rt>la</rt></ruby>",
+/// );
+///
+/// let ruby = Ruby::new().pronunciation(true);
+/// assert_eq!(
+///     ruby.render("한국어"),
+///     "<ruby><ruby>한국어<rt>hangugeo</rt></ruby><rt>[한구거]</rt></ruby>",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Ruby {
+    per_syllable: bool,
+    pronunciation: bool,
+}
+
+impl Ruby {
+    /// Creates a writer that annotates whole words with their romanization
+    /// only.
+    pub fn new() -> Ruby {
+        Ruby::default()
+    }
+
+    /// Sets whether each syllable is annotated separately.
+    ///
+    /// Syllables that cannot be romanized on their own are annotated
+    /// together with the following syllable, as described in
+    /// [`romanize_aligned_with`].
+    pub fn per_syllable(mut self, per_syllable: bool) -> Ruby {
+        self.per_syllable = per_syllable;
+        self
+    }
+
+    /// Sets whether the standard pronunciation of each word is added as a
+    /// second tier.
+    ///
+    /// This is the pronunciation romanization is based on, so tensification
+    /// is not shown.
+    pub fn pronunciation(mut self, pronunciation: bool) -> Ruby {
+        self.pronunciation = pronunciation;
+        self
+    }
+
+    /// Writes text as HTML annotated with Revised Romanization.
+    pub fn render(&self, input: &str) -> String {
+        self.render_with(input, &RevisedRomanization)
+    }
+
+    /// Writes text as HTML annotated using the given transcriber.
+    pub fn render_with<T>(&self, input: &str, transcriber: &T) -> String
+    where
+        T: Transcriber + ?Sized,
+    {
+        let mut res = String::new();
+
+        for token in Lexer::new(input.chars()) {
+            match token {
+                Token::Word(word) => {
+                    let spelling: &str = word.as_ref();
+
+                    if self.pronunciation {
+                        res.push_str("<ruby>");
+                    }
+
+                    res.push_str("<ruby>");
+
+                    if self.per_syllable {
+                        for segment in romanize_aligned_with(spelling, transcriber) {
+                            res.push_str(&spelling[segment.source()]);
+                            push_rt(&mut res, segment.text());
+                        }
+                    } else {
+                        res.push_str(spelling);
+                        push_rt(&mut res, &word.romanize_with(transcriber));
+                    }
+
+                    res.push_str("</ruby>");
+
+                    if self.pronunciation {
+                        let pronunciation = Pronouncer::new(false).pronounce(&word);
+                        push_rt(&mut res, &format!("[{}]", pronunciation));
+                        res.push_str("</ruby>");
+                    }
+                }
+                Token::Any(s) => push_escaped(&mut res, &s),
+            }
+        }
+
+        res
+    }
+}
+
+fn push_rt(dst: &mut String, s: &str) {
+    dst.push_str("<rt>");
+    push_escaped(dst, s);
+    dst.push_str("</rt>");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ruby;
+    use crate::{Popular, Yale};
+
+    #[test]
+    fn test_render() {
+        let ruby = Ruby::new();
+        assert_eq!(ruby.render(""), "");
+        assert_eq!(ruby.render("<b>"), "&lt;b&gt;");
+        assert_eq!(
+            ruby.render("\"사랑\""),
+            "&quot;<ruby>사랑<rt>sarang</rt></ruby>&quot;"
+        );
+    }
+
+    #[test]
+    fn test_render_per_syllable() {
+        let ruby = Ruby::new().per_syllable(true);
+
+        assert_eq!(
+            ruby.render("한국어"),
+            "<ruby>한<rt>han</rt>국<rt>gu</rt>어<rt>geo</rt></ruby>"
+        );
+        assert_eq!(
+            ruby.render_with("너무", &Popular::new()),
+            "<ruby>너무<rt>numoo</rt></ruby>"
+        );
+    }
+
+    #[test]
+    fn test_render_pronunciation() {
+        let ruby = Ruby::new().per_syllable(true).pronunciation(true);
+
+        assert_eq!(
+            ruby.render_with("없어", &Yale::new()),
+            "<ruby><ruby>없<rt>eps</rt>어<rt>.e</rt></ruby><rt>[업서]</rt></ruby>"
+        );
+    }
+}
@@ -0,0 +1,375 @@
+mod parser;
+
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use self::parser::{parse, Value};
+use crate::{Choseong, Context, Jongseong, Jungseong, Pronouncer, Syllable, Transcriber};
+
+/// An error returned when a scheme file is invalid
+///
+/// Lines are numbered from 1.
+#[derive(Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The line is not a section header, a key-value pair, a comment, or
+    /// blank, or its string is not terminated.
+    InvalidSyntax { line: usize },
+    /// The section is not one of `initial`, `medial`, `final`, or `joins`.
+    UnknownSection { line: usize, name: String },
+    /// The key is not a known option or a jamo (or pair of jamos, in
+    /// `joins`) valid in its section.
+    UnknownKey { line: usize, key: String },
+    /// The jamo (or pair of jamos) was already given in the same section.
+    DuplicateKey { line: usize, key: String },
+    /// The value has the wrong type for the key, e.g., a string for
+    /// `pronounce`.
+    InvalidValue { line: usize, key: String },
+    /// An initial consonant or a vowel has no transliteration. This is the
+    /// compatibility jamo (e.g., ㄱ) that is missing.
+    MissingJamo(char),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::InvalidSyntax { line } => write!(f, "line {}: invalid syntax", line),
+            ParseError::UnknownSection { line, name } => {
+                write!(f, "line {}: unknown section '{}'", line, name)
+            }
+            ParseError::UnknownKey { line, key } => {
+                write!(f, "line {}: unknown key '{}'", line, key)
+            }
+            ParseError::DuplicateKey { line, key } => {
+                write!(f, "line {}: duplicate key '{}'", line, key)
+            }
+            ParseError::InvalidValue { line, key } => {
+                write!(f, "line {}: invalid value for '{}'", line, key)
+            }
+            ParseError::MissingJamo(j) => write!(f, "missing transliteration for '{}'", j),
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// A romanization system defined by data
+///
+/// A scheme is a set of jamo tables that can be loaded at runtime, which
+/// allows variant systems to be maintained without recompiling. Scheme files
+/// are written in a subset of TOML:
+///
+/// ```toml
+/// name = "Example"
+/// pronounce = true # apply pronunciation rules first (default: true)
+/// strict = false   # use strict pronunciation rules (default: false)
+///
+/// [initial] # all 19 initial consonants
+/// "ㄱ" = "g"
+///
+/// [medial]  # all 21 vowels
+/// "ㅏ" = "a"
+///
+/// [final]   # final consonants; missing ones are written as is
+/// "ㄱ" = "k"
+///
+/// [joins]   # a final and the following initial written together
+/// "ㄹㄹ" = "ll"
+/// ```
+///
+/// See `schemes/revised_romanization.toml` for a complete example.
+///
+/// Only TOML is supported. The crate has no dependencies, so the format is
+/// parsed by hand, and a flat table of jamos is easier to write and review as
+/// TOML, which allows comments, than as JSON.
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::{romanize_with, Scheme};
+///
+/// let scheme: Scheme = include_str!("../../schemes/revised_romanization.toml")
+///     .parse()
+///     .unwrap();
+///
+/// assert_eq!(scheme.name(), Some("Revised Romanization"));
+/// assert_eq!(romanize_with("신라", &scheme), "silla");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Scheme {
+    name: Option<String>,
+    pronouncer: Option<Pronouncer>,
+    initials: HashMap<Choseong, String>,
+    medials: HashMap<Jungseong, String>,
+    finals: HashMap<Jongseong, String>,
+    joins: HashMap<(Jongseong, Choseong), String>,
+}
+
+impl Scheme {
+    /// Reads and parses a scheme file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read. Parse errors are returned
+    /// as `io::ErrorKind::InvalidData`.
+    pub fn from_path<P>(path: P) -> io::Result<Scheme>
+    where
+        P: AsRef<Path>,
+    {
+        let s = fs::read_to_string(path)?;
+        s.parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+}
+
+impl FromStr for Scheme {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Scheme, ParseError> {
+        let mut name = None;
+        let mut pronounce = true;
+        let mut strict = false;
+        let mut initials = HashMap::new();
+        let mut medials = HashMap::new();
+        let mut finals = HashMap::new();
+        let mut joins = HashMap::new();
+
+        for entry in parse(s)? {
+            let line = entry.line;
+            let key = entry.key;
+
+            let unknown_key = || ParseError::UnknownKey {
+                line,
+                key: key.clone(),
+            };
+            let duplicate_key = || ParseError::DuplicateKey {
+                line,
+                key: key.clone(),
+            };
+            let invalid_value = || ParseError::InvalidValue {
+                line,
+                key: key.clone(),
+            };
+
+            match entry.section.as_deref() {
+                None => match (key.as_str(), entry.value) {
+                    ("name", Value::String(s)) => name = Some(s),
+                    ("pronounce", Value::Boolean(b)) => pronounce = b,
+                    ("strict", Value::Boolean(b)) => strict = b,
+                    ("name", _) | ("pronounce", _) | ("strict", _) => return Err(invalid_value()),
+                    _ => return Err(unknown_key()),
+                },
+                Some(section) => {
+                    let value = match entry.value {
+                        Value::String(s) => s,
+                        Value::Boolean(_) => return Err(invalid_value()),
+                    };
+
+                    let jamos: Vec<char> = key.chars().collect();
+
+                    let duplicate = match (section, &jamos[..]) {
+                        ("initial", &[j]) => match Choseong::from_compat(j) {
+                            Some(j) => initials.insert(j, value).is_some(),
+                            None => return Err(unknown_key()),
+                        },
+                        ("medial", &[k]) => match Jungseong::from_compat(k) {
+                            Some(k) => medials.insert(k, value).is_some(),
+                            None => return Err(unknown_key()),
+                        },
+                        ("final", &[m]) => match Jongseong::from_compat(m) {
+                            Some(m) => finals.insert(m, value).is_some(),
+                            None => return Err(unknown_key()),
+                        },
+                        ("joins", &[m, j]) => {
+                            match (Jongseong::from_compat(m), Choseong::from_compat(j)) {
+                                (Some(m), Some(j)) => joins.insert((m, j), value).is_some(),
+                                _ => return Err(unknown_key()),
+                            }
+                        }
+                        // Section names are checked when parsing.
+                        _ => return Err(unknown_key()),
+                    };
+
+                    if duplicate {
+                        return Err(duplicate_key());
+                    }
+                }
+            }
+        }
+
+        for j in Choseong::ALL.iter() {
+            if !initials.contains_key(j) {
+                return Err(ParseError::MissingJamo(j.to_compat()));
+            }
+        }
+
+        for k in Jungseong::ALL.iter() {
+            if !medials.contains_key(k) {
+                return Err(ParseError::MissingJamo(k.to_compat()));
+            }
+        }
+
+        let pronouncer = if pronounce {
+            Some(Pronouncer::new(strict))
+        } else {
+            None
+        };
+
+        Ok(Scheme {
+            name,
+            pronouncer,
+            initials,
+            medials,
+            finals,
+            joins,
+        })
+    }
+}
+
+impl Transcriber for Scheme {
+    fn pronouncer(&self) -> Option<Pronouncer> {
+        self.pronouncer
+    }
+
+    fn transcribe(&self, syllables: &[Syllable], _: &Context) -> String {
+        let mut it = syllables.iter().peekable();
+        let mut res = String::new();
+
+        let mut skip = false;
+
+        while let Some(s) = it.next() {
+            let (j, k, m) = s.decompose();
+
+            if !skip {
+                // Every initial and medial is checked when parsing.
+                res.push_str(self.initials.get(&j).map_or("", String::as_str));
+            } else {
+                skip = false;
+            }
+
+            res.push_str(self.medials.get(&k).map_or("", String::as_str));
+
+            if let Some(m) = m {
+                let join = it.peek().and_then(|t| self.joins.get(&(m, t.choseong())));
+
+                if let Some(join) = join {
+                    res.push_str(join);
+                    skip = true;
+                    continue;
+                }
+
+                match self.finals.get(&m) {
+                    Some(t) => res.push_str(t),
+                    None => res.push(m.to_compat()),
+                }
+            }
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{romanize, romanize_with};
+
+    static REVISED_ROMANIZATION: &str = include_str!("../../schemes/revised_romanization.toml");
+
+    #[test]
+    fn test_revised_romanization() {
+        let scheme: Scheme = REVISED_ROMANIZATION.parse().unwrap();
+
+        for s in &[
+            "볼빨간사춘기",
+            "여보세요",
+            "우주소녀",
+            "러블리즈",
+            "신라",
+            "칼날",
+            "값",
+            "A 좋아",
+        ] {
+            assert_eq!(romanize_with(s, &scheme), romanize(s));
+        }
+    }
+
+    #[test]
+    fn test_missing_final() {
+        let scheme: Scheme = REVISED_ROMANIZATION
+            .replace("\"ㅎ\" = \"h\"\n\n[joins]", "[joins]")
+            .parse()
+            .unwrap();
+        assert_eq!(romanize_with("좋", &scheme), "joㅎ");
+    }
+
+    #[test]
+    fn test_cyrillic() {
+        let s = REVISED_ROMANIZATION
+            .replace("\"ㅅ\" = \"s\"", "\"ㅅ\" = \"с\"")
+            .replace("\"ㅏ\" = \"a\"", "\"ㅏ\" = \"а\"")
+            .replace("\"ㄹㄹ\" = \"ll\"", "\"ㄹㄹ\" = \"ль\"");
+        let scheme: Scheme = s.parse().unwrap();
+
+        assert_eq!(romanize_with("사", &scheme), "са");
+        assert_eq!(romanize_with("갈래", &scheme), "gальae");
+    }
+
+    #[test]
+    fn test_from_str_with_errors() {
+        assert_eq!(
+            "[initials]\n\"ㄱ\" = \"g\"".parse::<Scheme>().unwrap_err(),
+            ParseError::UnknownSection {
+                line: 1,
+                name: String::from("initials")
+            }
+        );
+
+        assert_eq!(
+            "name = \"a\"\n[bogus]".parse::<Scheme>().unwrap_err(),
+            ParseError::UnknownSection {
+                line: 2,
+                name: String::from("bogus")
+            }
+        );
+
+        assert_eq!(
+            "[initial]\n\"ㄳ\" = \"ks\"".parse::<Scheme>().unwrap_err(),
+            ParseError::UnknownKey {
+                line: 2,
+                key: String::from("ㄳ")
+            }
+        );
+
+        assert_eq!(
+            "[final]\n\"ㄳ\" = \"ks\"\n\"ㄳ\" = \"k\""
+                .parse::<Scheme>()
+                .unwrap_err(),
+            ParseError::DuplicateKey {
+                line: 3,
+                key: String::from("ㄳ")
+            }
+        );
+
+        assert_eq!(
+            "pronounce = \"yes\"".parse::<Scheme>().unwrap_err(),
+            ParseError::InvalidValue {
+                line: 1,
+                key: String::from("pronounce")
+            }
+        );
+
+        assert_eq!(
+            "".parse::<Scheme>().unwrap_err(),
+            ParseError::MissingJamo('ㄱ')
+        );
+    }
+}
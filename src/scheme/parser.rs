@@ -0,0 +1,266 @@
+//! A parser for the subset of TOML used by scheme files.
+//!
+//! Supported are comments, `[section]` headers, and `key = value` pairs,
+//! where keys are bare or quoted and values are strings or booleans.
+
+use std::iter::Peekable;
+use std::str::Chars;
+
+use super::ParseError;
+
+/// The sections of a scheme file.
+const SECTIONS: [&str; 4] = ["initial", "medial", "final", "joins"];
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Value {
+    String(String),
+    Boolean(bool),
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Entry {
+    pub line: usize,
+    pub section: Option<String>,
+    pub key: String,
+    pub value: Value,
+}
+
+pub fn parse(s: &str) -> Result<Vec<Entry>, ParseError> {
+    let mut entries = Vec::new();
+    let mut section = None;
+
+    for (i, raw_line) in s.lines().enumerate() {
+        let line = i + 1;
+        let mut it = raw_line.chars().peekable();
+
+        skip_whitespace(&mut it);
+
+        match it.peek() {
+            None | Some('#') => continue,
+            Some('[') => {
+                it.next();
+
+                let mut name = String::new();
+
+                loop {
+                    match it.next() {
+                        Some(']') => break,
+                        Some(c) => name.push(c),
+                        None => return Err(ParseError::InvalidSyntax { line }),
+                    }
+                }
+
+                let name = name.trim();
+
+                if name.is_empty() || !is_bare_key(name) {
+                    return Err(ParseError::InvalidSyntax { line });
+                }
+
+                if !SECTIONS.contains(&name) {
+                    return Err(ParseError::UnknownSection {
+                        line,
+                        name: name.to_string(),
+                    });
+                }
+
+                section = Some(name.to_string());
+            }
+            Some(_) => {
+                let key = parse_key(&mut it).ok_or(ParseError::InvalidSyntax { line })?;
+
+                skip_whitespace(&mut it);
+
+                if it.next() != Some('=') {
+                    return Err(ParseError::InvalidSyntax { line });
+                }
+
+                skip_whitespace(&mut it);
+
+                let value = parse_value(&mut it).ok_or(ParseError::InvalidSyntax { line })?;
+
+                entries.push(Entry {
+                    line,
+                    section: section.clone(),
+                    key,
+                    value,
+                });
+            }
+        }
+
+        skip_whitespace(&mut it);
+
+        match it.next() {
+            None | Some('#') => {}
+            Some(_) => return Err(ParseError::InvalidSyntax { line }),
+        }
+    }
+
+    Ok(entries)
+}
+
+fn skip_whitespace(it: &mut Peekable<Chars>) {
+    while let Some(&c) = it.peek() {
+        if c == ' ' || c == '\t' {
+            it.next();
+        } else {
+            break;
+        }
+    }
+}
+
+fn is_bare_key(s: &str) -> bool {
+    s.chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+fn parse_key(it: &mut Peekable<Chars>) -> Option<String> {
+    match it.peek() {
+        Some('"') | Some('\'') => parse_string(it),
+        _ => {
+            let mut key = String::new();
+
+            while let Some(&c) = it.peek() {
+                if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+                    key.push(c);
+                    it.next();
+                } else {
+                    break;
+                }
+            }
+
+            if key.is_empty() {
+                None
+            } else {
+                Some(key)
+            }
+        }
+    }
+}
+
+fn parse_value(it: &mut Peekable<Chars>) -> Option<Value> {
+    match it.peek() {
+        Some('"') | Some('\'') => parse_string(it).map(Value::String),
+        _ => {
+            let mut word = String::new();
+
+            while let Some(&c) = it.peek() {
+                if c.is_ascii_alphabetic() {
+                    word.push(c);
+                    it.next();
+                } else {
+                    break;
+                }
+            }
+
+            match word.as_str() {
+                "true" => Some(Value::Boolean(true)),
+                "false" => Some(Value::Boolean(false)),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Parses a basic ("...") or literal ('...') string.
+fn parse_string(it: &mut Peekable<Chars>) -> Option<String> {
+    let quote = it.next()?;
+    let mut res = String::new();
+
+    loop {
+        match it.next()? {
+            c if c == quote => return Some(res),
+            '\\' if quote == '"' => {
+                let c = match it.next()? {
+                    '"' => '"',
+                    '\\' => '\\',
+                    'n' => '\n',
+                    't' => '\t',
+                    'u' => parse_unicode_escape(it, 4)?,
+                    'U' => parse_unicode_escape(it, 8)?,
+                    _ => return None,
+                };
+
+                res.push(c);
+            }
+            c => res.push(c),
+        }
+    }
+}
+
+fn parse_unicode_escape(it: &mut Peekable<Chars>, len: usize) -> Option<char> {
+    let digits: String = it.take(len).collect();
+
+    if digits.len() != len {
+        return None;
+    }
+
+    u32::from_str_radix(&digits, 16)
+        .ok()
+        .and_then(std::char::from_u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let s = r#"
+# comment
+name = "test" # trailing comment
+pronounce = false
+
+[initial]
+"ㄱ" = 'g'
+'ㄲ' = "g\"\\"
+"#;
+
+        let entries = parse(s).unwrap();
+
+        assert_eq!(
+            entries,
+            vec![
+                Entry {
+                    line: 3,
+                    section: None,
+                    key: String::from("name"),
+                    value: Value::String(String::from("test")),
+                },
+                Entry {
+                    line: 4,
+                    section: None,
+                    key: String::from("pronounce"),
+                    value: Value::Boolean(false),
+                },
+                Entry {
+                    line: 7,
+                    section: Some(String::from("initial")),
+                    key: String::from("ㄱ"),
+                    value: Value::String(String::from("g")),
+                },
+                Entry {
+                    line: 8,
+                    section: Some(String::from("initial")),
+                    key: String::from("ㄲ"),
+                    value: Value::String(String::from("g\"\\")),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_with_invalid_syntax() {
+        assert_eq!(parse("a = b"), Err(ParseError::InvalidSyntax { line: 1 }));
+        assert_eq!(
+            parse("\na = \"b"),
+            Err(ParseError::InvalidSyntax { line: 2 })
+        );
+        assert_eq!(
+            parse("a = \"b\" c"),
+            Err(ParseError::InvalidSyntax { line: 1 })
+        );
+        assert_eq!(parse("[]"), Err(ParseError::InvalidSyntax { line: 1 }));
+        assert_eq!(parse("[a"), Err(ParseError::InvalidSyntax { line: 1 }));
+        assert_eq!(parse("= \"b\""), Err(ParseError::InvalidSyntax { line: 1 }));
+    }
+}
@@ -0,0 +1,419 @@
+use std::cmp;
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+
+use crate::{
+    romanize, romanize_with, Context, Pronouncer, RevisedRomanization, Syllable, Transcriber,
+};
+
+/// The cost of an insertion, deletion, or substitution, in half edits.
+const EDIT_COST: u32 = 2;
+
+/// The cost of substituting commonly confused letters, e.g., o and u.
+const CONFUSION_COST: u32 = 1;
+
+/// Pairs of letters that are commonly confused in informal romanization,
+/// after folding. `N` stands for ng.
+static CONFUSIONS: [(char, char); 4] = [('o', 'u'), ('e', 'i'), ('r', 'l'), ('N', 'n')];
+
+/// A match returned by [`SearchIndex::search`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hit<'a> {
+    id: usize,
+    text: &'a str,
+    distance: f32,
+}
+
+impl<'a> Hit<'a> {
+    /// Returns the id of the entry, as returned by [`SearchIndex::insert`].
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// Returns the number of edits between the query and the entry.
+    ///
+    /// Substituting commonly confused letters counts as half an edit.
+    pub fn distance(&self) -> f32 {
+        self.distance
+    }
+}
+
+/// A node of the BK-tree, holding a folded key and the entries indexed by it
+#[derive(Debug)]
+struct Node {
+    key: Vec<char>,
+    ids: Vec<usize>,
+    /// Child nodes by their distance from this node's key.
+    children: BTreeMap<u32, usize>,
+}
+
+/// An index of Hangeul text searchable by approximate romanization
+///
+/// Each entry is indexed by its Revised Romanization and by the romanization
+/// of its strict pronunciation, which reflects tensification (e.g., 국밥 =>
+/// "gukppap"). Queries and keys are folded to reduce common differences in
+/// informal spellings: eo and o, eu and u, ae and e, oo and u, ee and i,
+/// k and g, ch and j, t and d, p and b, and doubled letters are treated as
+/// the same. What remains is compared by edit distance, where substituting
+/// commonly confused letters (o/u, e/i, r/l, ng/n) costs half an edit.
+///
+/// Hangeul queries are romanized before searching.
+///
+/// Keys are stored in a BK-tree, so a search only scores the keys that the
+/// triangle inequality cannot rule out. Close searches skip most of the
+/// index, but the number of keys visited grows with `max_distance`, and
+/// each comparison takes time proportional to the product of the key
+/// lengths.
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::SearchIndex;
+///
+/// let index: SearchIndex = vec!["안녕", "우주소녀", "러블리즈"].into_iter().collect();
+///
+/// for query in &["anyong", "annyong", "anyeong"] {
+///     assert_eq!(index.search(query, 1.0)[0].text(), "안녕");
+/// }
+///
+/// assert_eq!(index.search("woojoo sonyo", 1.0)[0].text(), "우주소녀");
+/// ```
+#[derive(Debug, Default)]
+pub struct SearchIndex {
+    texts: Vec<String>,
+    nodes: Vec<Node>,
+}
+
+impl SearchIndex {
+    pub fn new() -> SearchIndex {
+        SearchIndex::default()
+    }
+
+    /// Adds an entry to the index and returns its id.
+    pub fn insert<S>(&mut self, text: S) -> usize
+    where
+        S: Into<String>,
+    {
+        let text = text.into();
+
+        let mut keys = vec![fold(&romanize(&text))];
+
+        let pronunciation = fold(&romanize_with(&text, &StrictRevisedRomanization));
+
+        if !keys.contains(&pronunciation) {
+            keys.push(pronunciation);
+        }
+
+        let id = self.texts.len();
+        self.texts.push(text);
+
+        for key in keys {
+            self.insert_key(key, id);
+        }
+
+        id
+    }
+
+    fn insert_key(&mut self, key: Vec<char>, id: usize) {
+        if self.nodes.is_empty() {
+            self.nodes.push(Node {
+                key,
+                ids: vec![id],
+                children: BTreeMap::new(),
+            });
+            return;
+        }
+
+        let mut i = 0;
+
+        loop {
+            let cost = distance(&key, &self.nodes[i].key);
+
+            if cost == 0 {
+                self.nodes[i].ids.push(id);
+                return;
+            }
+
+            match self.nodes[i].children.get(&cost) {
+                Some(&child) => i = child,
+                None => {
+                    let child = self.nodes.len();
+                    self.nodes[i].children.insert(cost, child);
+                    self.nodes.push(Node {
+                        key,
+                        ids: vec![id],
+                        children: BTreeMap::new(),
+                    });
+                    return;
+                }
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.texts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.texts.is_empty()
+    }
+
+    /// Returns entries within `max_distance` edits of the query, closest
+    /// first.
+    pub fn search(&self, query: &str, max_distance: f32) -> Vec<Hit<'_>> {
+        let query = fold(&romanize(query));
+
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let max_cost = (max_distance * EDIT_COST as f32) as u32;
+
+        // The lowest cost of each entry, over all of its keys
+        let mut costs = BTreeMap::new();
+        let mut stack = if self.nodes.is_empty() {
+            Vec::new()
+        } else {
+            vec![0]
+        };
+
+        while let Some(i) = stack.pop() {
+            let node = &self.nodes[i];
+            let cost = distance(&query, &node.key);
+
+            if cost <= max_cost {
+                for &id in &node.ids {
+                    let min = costs.entry(id).or_insert(cost);
+                    *min = cmp::min(*min, cost);
+                }
+            }
+
+            let range = cost.saturating_sub(max_cost)..=cost.saturating_add(max_cost);
+            stack.extend(node.children.range(range).map(|(_, &child)| child));
+        }
+
+        let mut hits: Vec<_> = costs
+            .into_iter()
+            .map(|(id, cost)| Hit {
+                id,
+                text: &self.texts[id],
+                distance: cost as f32 / EDIT_COST as f32,
+            })
+            .collect();
+
+        hits.sort_by(|a, b| {
+            a.distance
+                .partial_cmp(&b.distance)
+                .unwrap_or(cmp::Ordering::Equal)
+                .then(a.id.cmp(&b.id))
+        });
+
+        hits
+    }
+}
+
+impl<S> FromIterator<S> for SearchIndex
+where
+    S: Into<String>,
+{
+    fn from_iter<I>(iter: I) -> SearchIndex
+    where
+        I: IntoIterator<Item = S>,
+    {
+        let mut index = SearchIndex::new();
+
+        for text in iter {
+            index.insert(text);
+        }
+
+        index
+    }
+}
+
+/// Revised Romanization of the strict pronunciation
+struct StrictRevisedRomanization;
+
+impl Transcriber for StrictRevisedRomanization {
+    fn pronouncer(&self) -> Option<Pronouncer> {
+        Some(Pronouncer::new(true))
+    }
+
+    fn transcribe(&self, syllables: &[Syllable], ctx: &Context) -> String {
+        RevisedRomanization.transcribe(syllables, ctx)
+    }
+}
+
+/// Reduces a romanized string to a key that ignores common spelling
+/// differences.
+fn fold(s: &str) -> Vec<char> {
+    let s: String = s
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+
+    let s = s
+        .replace("eo", "o")
+        .replace("eu", "u")
+        .replace("oo", "u")
+        .replace("ee", "i")
+        .replace("ae", "e")
+        .replace("ch", "j")
+        .replace("sh", "s")
+        .replace("ng", "N");
+
+    let mut res: Vec<char> = Vec::with_capacity(s.len());
+
+    for c in s.chars() {
+        let c = match c {
+            'g' => 'k',
+            'd' => 't',
+            'b' => 'p',
+            _ => c,
+        };
+
+        if res.last() != Some(&c) {
+            res.push(c);
+        }
+    }
+
+    res
+}
+
+/// Calculates the weighted Levenshtein distance between two strings.
+fn distance(a: &[char], b: &[char]) -> u32 {
+    let mut prev: Vec<u32> = (0..=b.len() as u32).map(|i| i * EDIT_COST).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for (i, &c) in a.iter().enumerate() {
+        curr[0] = (i as u32 + 1) * EDIT_COST;
+
+        for (j, &d) in b.iter().enumerate() {
+            let substitution = prev[j] + substitution_cost(c, d);
+            let deletion = prev[j + 1] + EDIT_COST;
+            let insertion = curr[j] + EDIT_COST;
+            curr[j + 1] = cmp::min(substitution, cmp::min(deletion, insertion));
+        }
+
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
+
+fn substitution_cost(a: char, b: char) -> u32 {
+    if a == b {
+        0
+    } else if CONFUSIONS.contains(&(a, b)) || CONFUSIONS.contains(&(b, a)) {
+        CONFUSION_COST
+    } else {
+        EDIT_COST
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn f(s: &str) -> String {
+        fold(s).into_iter().collect()
+    }
+
+    #[test]
+    fn test_fold() {
+        assert_eq!(f("annyeong"), "anyoN");
+        assert_eq!(f("anyong"), "anyoN");
+        assert_eq!(f("hanguk"), "haNuk");
+        assert_eq!(f("Woo-joo"), "wuju");
+        assert_eq!(f("gimchi"), "kimji");
+        assert_eq!(f("saeroun"), "seroun");
+    }
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(distance(&fold("sarang"), &fold("sarang")), 0);
+        assert_eq!(distance(&fold("nuh"), &fold("no")), 3);
+        assert_eq!(distance(&fold("sarang"), &fold("salang")), 1);
+        assert_eq!(distance(&fold("sarang"), &fold("sarag")), 2);
+        assert_eq!(distance(&fold(""), &fold("ab")), 4);
+    }
+
+    #[test]
+    fn test_search() {
+        let mut index = SearchIndex::new();
+        let a = index.insert("안녕");
+        let b = index.insert("사랑해");
+        let c = index.insert("국밥");
+
+        assert_eq!(index.len(), 3);
+
+        let hits = index.search("anyong", 1.0);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].id(), a);
+        assert_eq!(hits[0].distance(), 0.0);
+
+        assert_eq!(index.search("salanghe", 1.0)[0].id(), b);
+        assert_eq!(index.search("kukppap", 0.0)[0].id(), c);
+        assert_eq!(index.search("사랑해", 0.0)[0].id(), b);
+
+        assert!(index.search("xyz", 1.0).is_empty());
+        assert!(index.search("", 10.0).is_empty());
+    }
+
+    #[test]
+    fn test_search_matches_linear_scan() {
+        let texts: Vec<String> = (0..500u32)
+            .map(|i| {
+                (0..1 + i % 4)
+                    .map(|j| {
+                        std::char::from_u32(0xac00 + (i * 7919 + j * 104_729) % 11_172).unwrap()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let index: SearchIndex = texts.iter().map(String::as_str).collect();
+
+        let queries = ["anyong", "salang", "kukpap", "hanguk", "u", "sonyo"]
+            .iter()
+            .map(|s| s.to_string())
+            .chain(texts.iter().step_by(25).map(|text| romanize(text)));
+
+        for query in queries {
+            for &max_distance in &[0.0, 1.0, 2.5] {
+                let folded = fold(&query);
+                let max_cost = (max_distance * EDIT_COST as f32) as u32;
+
+                let expected: Vec<_> = texts
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(id, text)| {
+                        let keys = [
+                            fold(&romanize(text)),
+                            fold(&romanize_with(text, &StrictRevisedRomanization)),
+                        ];
+
+                        keys.iter()
+                            .map(|key| distance(&folded, key))
+                            .min()
+                            .filter(|&cost| cost <= max_cost)
+                            .map(|cost| (cost as f32 / EDIT_COST as f32, id))
+                    })
+                    .collect();
+
+                let mut actual: Vec<_> = index
+                    .search(&query, max_distance)
+                    .iter()
+                    .map(|hit| (hit.distance(), hit.id()))
+                    .collect();
+
+                actual.sort_by_key(|&(_, id)| id);
+                assert_eq!(actual, expected, "{} within {}", query, max_distance);
+            }
+        }
+    }
+}
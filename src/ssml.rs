@@ -0,0 +1,214 @@
+use crate::{Ipa, Lexer, Token, Transcriber};
+
+/// A Speech Synthesis Markup Language (SSML) writer
+///
+/// Text is wrapped in a `speak` element. Words whose standard pronunciation
+/// differs from their spelling are wrapped in a `phoneme` element with
+/// their IPA transcription, so that speech synthesis engines do not have to
+/// guess it. All other text is passed through.
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::Ssml;
+///
+/// let ssml = Ssml::new();
+///
+/// assert_eq!(
+///     ssml.render("국물 <물>"),
+///     concat!(
+///         r#"<speak version="1.1" xmlns="http://www.w3.org/2001/10/synthesis" xml:lang="ko-KR">"#,
+///         r#"<phoneme alphabet="ipa" ph="kuŋmul">국물</phoneme> &lt;물&gt;"#,
+///         "</speak>",
+///     )
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Ssml {
+    ipa: Ipa,
+}
+
+impl Ssml {
+    /// Creates a writer using broad IPA transcriptions.
+    pub fn new() -> Ssml {
+        Ssml { ipa: Ipa::broad() }
+    }
+
+    /// Sets the IPA transcriber used for pronunciations.
+    pub fn ipa(mut self, ipa: Ipa) -> Ssml {
+        self.ipa = ipa;
+        self
+    }
+
+    /// Writes text as an SSML document.
+    pub fn render(&self, input: &str) -> String {
+        let mut res = String::from(
+            r#"<speak version="1.1" xmlns="http://www.w3.org/2001/10/synthesis" xml:lang="ko-KR">"#,
+        );
+
+        for token in Lexer::new(input.chars()) {
+            match token {
+                Token::Word(word) => {
+                    let spelling: &str = word.as_ref();
+
+                    let pronunciation = match self.ipa.pronouncer() {
+                        Some(pronouncer) => pronouncer.pronounce(&word),
+                        None => word.clone(),
+                    };
+
+                    if pronunciation == word {
+                        res.push_str(spelling);
+                    } else {
+                        res.push_str(r#"<phoneme alphabet="ipa" ph=""#);
+                        push_escaped(&mut res, &word.romanize_with(&self.ipa));
+                        res.push_str(r#"">"#);
+                        res.push_str(spelling);
+                        res.push_str("</phoneme>");
+                    }
+                }
+                Token::Any(s) => push_escaped(&mut res, &s),
+            }
+        }
+
+        res.push_str("</speak>");
+
+        res
+    }
+}
+
+impl Default for Ssml {
+    fn default() -> Ssml {
+        Ssml::new()
+    }
+}
+
+/// Appends text escaped for use in both XML character data and attribute
+/// values.
+pub(crate) fn push_escaped(dst: &mut String, s: &str) {
+    for c in s.chars() {
+        match c {
+            '&' => dst.push_str("&amp;"),
+            '<' => dst.push_str("&lt;"),
+            '>' => dst.push_str("&gt;"),
+            '"' => dst.push_str("&quot;"),
+            '\'' => dst.push_str("&apos;"),
+            _ => dst.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ssml;
+    use crate::Ipa;
+
+    /// Checks that `s` is a well-formed XML element.
+    ///
+    /// This only supports the subset of XML written by `Ssml`: elements,
+    /// quoted attributes, character data, and the predefined entities.
+    fn assert_well_formed(s: &str) {
+        let mut stack: Vec<&str> = Vec::new();
+        let mut rest = s;
+        let mut closed_root = false;
+
+        while !rest.is_empty() {
+            assert!(!closed_root, "content after root element: {}", rest);
+
+            if let Some(tail) = rest.strip_prefix('<') {
+                let end = tail.find('>').expect("unterminated tag");
+                let tag = &tail[..end];
+                rest = &tail[end + 1..];
+
+                if let Some(name) = tag.strip_prefix('/') {
+                    assert_eq!(stack.pop(), Some(name), "mismatched end tag");
+                    closed_root = stack.is_empty();
+                } else {
+                    let (name, attrs) = tag.split_at(tag.find(' ').unwrap_or(tag.len()));
+                    assert!(!name.is_empty(), "empty tag name");
+
+                    let mut attrs = attrs.trim_start();
+
+                    while !attrs.is_empty() {
+                        let eq = attrs.find("=\"").expect("unquoted attribute");
+                        let value = &attrs[eq + 2..];
+                        let end = value.find('"').expect("unterminated attribute");
+                        assert_text(&value[..end]);
+                        attrs = value[end + 1..].trim_start();
+                    }
+
+                    stack.push(name);
+                }
+            } else {
+                assert!(!stack.is_empty(), "text outside root element: {}", rest);
+                let end = rest.find('<').unwrap_or(rest.len());
+                assert_text(&rest[..end]);
+                rest = &rest[end..];
+            }
+        }
+
+        assert!(closed_root, "unclosed elements: {:?}", stack);
+    }
+
+    fn assert_text(s: &str) {
+        assert!(
+            !s.contains('<') && !s.contains('"'),
+            "unescaped text: {}",
+            s
+        );
+
+        for (i, _) in s.match_indices('&') {
+            let entity = &s[i..s[i..]
+                .find(';')
+                .map(|j| i + j + 1)
+                .expect("unterminated entity")];
+            assert!(
+                ["&amp;", "&lt;", "&gt;", "&quot;", "&apos;"].contains(&entity),
+                "unknown entity: {}",
+                entity
+            );
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let ssml = Ssml::new();
+        let s = ssml.render("한국어 & 영어");
+
+        assert!(s.contains(r#"<phoneme alphabet="ipa" ph="hankukʌ">한국어</phoneme>"#));
+        assert!(s.contains(" &amp; 영어</speak>"));
+        assert_well_formed(&s);
+    }
+
+    #[test]
+    fn test_render_with_narrow_ipa() {
+        let ssml = Ssml::new().ipa(Ipa::narrow());
+        let s = ssml.render("사랑");
+        assert!(s.contains("사랑"));
+        assert!(!s.contains("<phoneme"));
+
+        let s = ssml.render("신라");
+        assert!(s.contains(r#"ph="ɕilla""#));
+    }
+
+    #[test]
+    fn test_well_formed() {
+        let ssml = Ssml::new();
+
+        for input in &[
+            "",
+            "<speak>",
+            "\"따옴표\" & '작은따옴표'",
+            "a < b > c",
+            "&amp; 있다",
+            "</phoneme>값이",
+        ] {
+            assert_well_formed(&ssml.render(input));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_assert_well_formed() {
+        assert_well_formed("<speak>a & b</speak>");
+    }
+}
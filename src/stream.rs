@@ -0,0 +1,191 @@
+use std::io::{self, Read, Write};
+use std::str;
+
+use crate::{romanize_with, RevisedRomanization, Syllable, Transcriber};
+
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// Romanizes a stream of UTF-8 text using Revised Romanization rules.
+///
+/// See [`romanize_stream_with`].
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::romanize_stream;
+///
+/// let mut output = Vec::new();
+/// romanize_stream("여보세요".as_bytes(), &mut output)?;
+/// assert_eq!(output, b"yeoboseyo");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn romanize_stream<R, W>(reader: R, writer: W) -> io::Result<()>
+where
+    R: Read,
+    W: Write,
+{
+    romanize_stream_with(reader, writer, &RevisedRomanization)
+}
+
+/// Romanizes a stream of UTF-8 text using the given transcriber.
+///
+/// The input is read in chunks and written as soon as it is romanized, so
+/// only the current chunk is held in memory. A chunk is never split within a
+/// word, as pronunciation depends on the whole word; it is extended until
+/// the word ends instead.
+///
+/// # Errors
+///
+/// Errors from `reader` and `writer` are returned as is. If the input is
+/// not valid UTF-8, an error of kind [`io::ErrorKind::InvalidData`] is
+/// returned with the byte offset of the invalid sequence. All text before
+/// it is written.
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+///
+/// use hangeul::{romanize_stream_with, Yale};
+///
+/// let mut output = Vec::new();
+/// romanize_stream_with("한국어".as_bytes(), &mut output, &Yale::new())?;
+/// assert_eq!(output, b"hankwuk.e");
+///
+/// let err = romanize_stream_with(&b"\xea\xb0\x80 \xff"[..], io::sink(), &Yale::new())
+///     .unwrap_err();
+/// assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+/// assert_eq!(err.to_string(), "invalid UTF-8 at byte 4");
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn romanize_stream_with<R, W, T>(
+    mut reader: R,
+    mut writer: W,
+    transcriber: &T,
+) -> io::Result<()>
+where
+    R: Read,
+    W: Write,
+    T: Transcriber + ?Sized,
+{
+    let mut buf = vec![0; CHUNK_SIZE];
+
+    // Bytes read but not yet written, i.e., an incomplete character or the
+    // start of a word.
+    let mut pending = Vec::new();
+    let mut offset = 0;
+
+    loop {
+        let n = match reader.read(&mut buf) {
+            Ok(n) => n,
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+
+        let is_eof = n == 0;
+        pending.extend_from_slice(&buf[..n]);
+
+        let (text, error) = match str::from_utf8(&pending) {
+            Ok(s) => (s, None),
+            Err(e) => {
+                let s = str::from_utf8(&pending[..e.valid_up_to()])
+                    .map_err(|_| invalid_utf8(offset))?;
+
+                if e.error_len().is_some() || is_eof {
+                    (s, Some(invalid_utf8(offset + e.valid_up_to())))
+                } else {
+                    (s, None)
+                }
+            }
+        };
+
+        let end = if is_eof || error.is_some() {
+            text.len()
+        } else {
+            text.char_indices()
+                .rfind(|&(_, c)| !Syllable::is_valid(c))
+                .map_or(0, |(i, c)| i + c.len_utf8())
+        };
+
+        writer.write_all(romanize_with(&text[..end], transcriber).as_bytes())?;
+
+        if let Some(error) = error {
+            writer.flush()?;
+            return Err(error);
+        }
+
+        pending.drain(..end);
+        offset += end;
+
+        if is_eof {
+            break;
+        }
+    }
+
+    writer.flush()
+}
+
+fn invalid_utf8(offset: usize) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("invalid UTF-8 at byte {}", offset),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Read};
+
+    use super::romanize_stream;
+    use crate::romanize;
+
+    /// A reader that returns at most `n` bytes at a time.
+    struct Trickle<'a> {
+        data: &'a [u8],
+        n: usize,
+    }
+
+    impl<'a> Read for Trickle<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = self.n.min(buf.len()).min(self.data.len());
+            buf[..n].copy_from_slice(&self.data[..n]);
+            self.data = &self.data[n..];
+            Ok(n)
+        }
+    }
+
+    fn r(data: &[u8], n: usize) -> io::Result<String> {
+        let mut output = Vec::new();
+        romanize_stream(Trickle { data, n }, &mut output)?;
+        Ok(String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn test_romanize_stream() -> io::Result<()> {
+        let input = "신라의 달밤, 종로에서 (한국어) 없어요!";
+
+        for n in 1..8 {
+            assert_eq!(r(input.as_bytes(), n)?, romanize(input));
+        }
+
+        let input = "가나다 ".repeat(10_000);
+        assert_eq!(r(input.as_bytes(), 8192)?, romanize(&input));
+
+        assert_eq!(r(b"", 1)?, "");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_romanize_stream_with_invalid_utf8() {
+        let mut output = Vec::new();
+        let err = romanize_stream(&b"\xea\xb0\x80\xb0"[..], &mut output).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), "invalid UTF-8 at byte 3");
+        assert_eq!(output, b"ga");
+
+        // A truncated character at the end of the input.
+        let err = r(b"ab\xea\xb0", 1).unwrap_err();
+        assert_eq!(err.to_string(), "invalid UTF-8 at byte 2");
+    }
+}
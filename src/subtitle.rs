@@ -0,0 +1,390 @@
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{
+    romanize_html_with, romanize_with, Context, RevisedRomanization, Syllable, Transcriber,
+};
+
+/// A subtitle or lyric file format
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SubtitleFormat {
+    /// SubRip (.srt)
+    Srt,
+    /// Web Video Text Tracks (.vtt)
+    WebVtt,
+    /// Lyrics (.lrc), including enhanced word timestamps
+    Lrc,
+}
+
+impl SubtitleFormat {
+    /// Guesses the format of a file from its contents.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hangeul::SubtitleFormat;
+    ///
+    /// assert_eq!(SubtitleFormat::detect("WEBVTT\n"), Some(SubtitleFormat::WebVtt));
+    /// assert_eq!(SubtitleFormat::detect("[00:01.00]가사\n"), Some(SubtitleFormat::Lrc));
+    /// assert_eq!(SubtitleFormat::detect("가사\n"), None);
+    /// ```
+    pub fn detect(input: &str) -> Option<SubtitleFormat> {
+        let input = input.trim_start_matches('\u{feff}');
+
+        if input.starts_with("WEBVTT") {
+            Some(SubtitleFormat::WebVtt)
+        } else if input.contains("-->") {
+            Some(SubtitleFormat::Srt)
+        } else if input.trim_start().starts_with('[') {
+            Some(SubtitleFormat::Lrc)
+        } else {
+            None
+        }
+    }
+}
+
+impl FromStr for SubtitleFormat {
+    type Err = ParseSubtitleFormatError;
+
+    /// Parses a format from its file extension, e.g., `srt`.
+    fn from_str(s: &str) -> Result<SubtitleFormat, ParseSubtitleFormatError> {
+        match s.to_ascii_lowercase().as_str() {
+            "srt" => Ok(SubtitleFormat::Srt),
+            "vtt" => Ok(SubtitleFormat::WebVtt),
+            "lrc" => Ok(SubtitleFormat::Lrc),
+            _ => Err(ParseSubtitleFormatError(s.to_string())),
+        }
+    }
+}
+
+/// An error returned when a file extension is not a known subtitle format
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::SubtitleFormat;
+///
+/// let e = "txt".parse::<SubtitleFormat>().unwrap_err();
+/// assert_eq!(e.to_string(), "unknown subtitle format 'txt'");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseSubtitleFormatError(String);
+
+impl fmt::Display for ParseSubtitleFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown subtitle format '{}'", self.0)
+    }
+}
+
+impl error::Error for ParseSubtitleFormatError {}
+
+/// A converter for the text of subtitle and lyric files
+///
+/// Only cue and lyric text is converted. Indices, cue identifiers, timings
+/// and settings, word timestamps, headers, metadata, and styling tags are
+/// left as is, as are line endings.
+///
+/// Text can either be romanized or rewritten in Hangeul as it is pronounced.
+/// When `dual` is set, the converted text is added after the original text
+/// of each cue (or, for LRC, as a second line with the same timestamps).
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::{SubtitleFormat, Subtitles};
+///
+/// let input = "1\n00:00:01,000 --> 00:00:02,500\n<i>안녕하세요</i>\n";
+///
+/// let srt = Subtitles::new(SubtitleFormat::Srt);
+/// assert_eq!(
+///     srt.romanize(input),
+///     "1\n00:00:01,000 --> 00:00:02,500\n<i>annyeonghaseyo</i>\n",
+/// );
+///
+/// let srt = Subtitles::new(SubtitleFormat::Srt).pronounce(true).dual(true);
+/// assert_eq!(
+///     srt.romanize("1\n00:00:01,000 --> 00:00:02,500\n한국어\n"),
+///     "1\n00:00:01,000 --> 00:00:02,500\n한국어\n한구거\n",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Subtitles {
+    format: SubtitleFormat,
+    pronounce: bool,
+    dual: bool,
+}
+
+impl Subtitles {
+    pub fn new(format: SubtitleFormat) -> Subtitles {
+        Subtitles {
+            format,
+            pronounce: false,
+            dual: false,
+        }
+    }
+
+    /// Sets whether text is rewritten as it is pronounced instead of
+    /// romanized.
+    pub fn pronounce(mut self, pronounce: bool) -> Subtitles {
+        self.pronounce = pronounce;
+        self
+    }
+
+    /// Sets whether the original text is kept with the converted text.
+    pub fn dual(mut self, dual: bool) -> Subtitles {
+        self.dual = dual;
+        self
+    }
+
+    /// Converts the text of a file using Revised Romanization rules.
+    pub fn romanize(&self, input: &str) -> String {
+        self.romanize_with(input, &RevisedRomanization)
+    }
+
+    /// Converts the text of a file using the given transcriber.
+    ///
+    /// The transcriber is not used when pronouncing.
+    pub fn romanize_with<T>(&self, input: &str, transcriber: &T) -> String
+    where
+        T: Transcriber + ?Sized,
+    {
+        if self.pronounce {
+            self.convert(input, &Pronunciation)
+        } else {
+            self.convert(input, transcriber)
+        }
+    }
+
+    fn convert<T>(&self, input: &str, transcriber: &T) -> String
+    where
+        T: Transcriber + ?Sized,
+    {
+        match self.format {
+            SubtitleFormat::Srt | SubtitleFormat::WebVtt => self.convert_cues(input, transcriber),
+            SubtitleFormat::Lrc => self.convert_lyrics(input, transcriber),
+        }
+    }
+
+    /// Converts the text of SRT and WebVTT cues, i.e., the lines following
+    /// a timing line (`... --> ...`) up to the next blank line.
+    fn convert_cues<T>(&self, input: &str, transcriber: &T) -> String
+    where
+        T: Transcriber + ?Sized,
+    {
+        let mut res = String::with_capacity(input.len());
+        let mut converted = String::new();
+        let mut in_cue_text = false;
+        let mut last_eol = "\n";
+
+        for line in input.split_inclusive('\n') {
+            let (text, eol) = split_line_ending(line);
+
+            if !eol.is_empty() {
+                last_eol = eol;
+            }
+
+            if text.trim().is_empty() {
+                res.push_str(&converted);
+                converted.clear();
+                res.push_str(line);
+                in_cue_text = false;
+            } else if in_cue_text {
+                let romanized = romanize_html_with(text, transcriber);
+
+                if self.dual {
+                    res.push_str(line);
+                    converted.push_str(&romanized);
+                    converted.push_str(last_eol);
+                } else {
+                    res.push_str(&romanized);
+                    res.push_str(eol);
+                }
+            } else {
+                res.push_str(line);
+                in_cue_text = text.contains("-->");
+            }
+        }
+
+        if res.ends_with('\n') {
+            res.push_str(&converted);
+        } else if !converted.is_empty() {
+            // The last line has no line ending, so neither does the last
+            // converted line.
+            res.push_str(last_eol);
+            res.push_str(converted.trim_end_matches(&['\r', '\n'][..]));
+        }
+
+        res
+    }
+
+    /// Converts the text of LRC lines, i.e., lines starting with one or
+    /// more timestamps (`[mm:ss.xx]`). Metadata lines (e.g., `[ar:...]`)
+    /// are left as is.
+    fn convert_lyrics<T>(&self, input: &str, transcriber: &T) -> String
+    where
+        T: Transcriber + ?Sized,
+    {
+        let mut res = String::with_capacity(input.len());
+        let mut last_eol = "\n";
+
+        for line in input.split_inclusive('\n') {
+            let (text, eol) = split_line_ending(line);
+            let tags_len = timestamps_len(text);
+
+            if !eol.is_empty() {
+                last_eol = eol;
+            }
+
+            if tags_len == 0 {
+                res.push_str(line);
+                continue;
+            }
+
+            let (tags, lyric) = text.split_at(tags_len);
+
+            // Word timestamps (`<mm:ss.xx>`) are not Hangeul and are thus
+            // left as is.
+            let romanized = romanize_with(lyric, transcriber);
+
+            if self.dual {
+                res.push_str(text);
+                res.push_str(last_eol);
+            }
+
+            res.push_str(tags);
+            res.push_str(&romanized);
+            res.push_str(eol);
+        }
+
+        res
+    }
+}
+
+/// Rewrites words in Hangeul as they are pronounced.
+struct Pronunciation;
+
+impl Transcriber for Pronunciation {
+    fn transcribe(&self, syllables: &[Syllable], _: &Context) -> String {
+        syllables.iter().map(|s| s.as_char()).collect()
+    }
+}
+
+fn split_line_ending(line: &str) -> (&str, &str) {
+    let text = line.trim_end_matches(&['\r', '\n'][..]);
+    line.split_at(text.len())
+}
+
+/// Returns the length of the leading LRC timestamps, e.g., `[00:12.34]`.
+fn timestamps_len(line: &str) -> usize {
+    let mut len = 0;
+
+    while let Some(rest) = line[len..].strip_prefix('[') {
+        let is_timestamp = rest.starts_with(|c: char| c.is_ascii_digit());
+
+        match rest.find(']') {
+            Some(end) if is_timestamp => len += end + 2,
+            _ => break,
+        }
+    }
+
+    len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseSubtitleFormatError, SubtitleFormat, Subtitles};
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("SRT".parse(), Ok(SubtitleFormat::Srt));
+        assert_eq!("vtt".parse(), Ok(SubtitleFormat::WebVtt));
+        assert_eq!("lrc".parse(), Ok(SubtitleFormat::Lrc));
+        assert_eq!(
+            "txt".parse::<SubtitleFormat>(),
+            Err(ParseSubtitleFormatError(String::from("txt")))
+        );
+    }
+
+    #[test]
+    fn test_srt() {
+        let input = "\u{feff}1\r\n00:00:01,000 --> 00:00:02,000\r\n{\\an8}<font color=\"#ff0000\">사랑해</font>\r\n\r\n2\r\n00:00:03,000 --> 00:00:04,000\r\n신라\r\n여보세요";
+
+        let srt = Subtitles::new(SubtitleFormat::Srt);
+        assert_eq!(
+            srt.romanize(input),
+            "\u{feff}1\r\n00:00:01,000 --> 00:00:02,000\r\n{\\an8}<font color=\"#ff0000\">saranghae</font>\r\n\r\n2\r\n00:00:03,000 --> 00:00:04,000\r\nsilla\r\nyeoboseyo"
+        );
+
+        let srt = srt.dual(true);
+        assert_eq!(
+            srt.romanize(input),
+            "\u{feff}1\r\n00:00:01,000 --> 00:00:02,000\r\n{\\an8}<font color=\"#ff0000\">사랑해</font>\r\n{\\an8}<font color=\"#ff0000\">saranghae</font>\r\n\r\n2\r\n00:00:03,000 --> 00:00:04,000\r\n신라\r\n여보세요\r\nsilla\r\nyeoboseyo"
+        );
+    }
+
+    #[test]
+    fn test_web_vtt() {
+        let input = "\
+WEBVTT - 한국어
+
+NOTE 메모
+
+STYLE
+::cue(.노래) { color: yellow }
+
+인사
+00:01.000 --> 00:02.000 align:start
+<v 철수>안녕 <00:01.500><c.노래>친구</c> &amp; 너</v>
+";
+
+        let expected = "\
+WEBVTT - 한국어
+
+NOTE 메모
+
+STYLE
+::cue(.노래) { color: yellow }
+
+인사
+00:01.000 --> 00:02.000 align:start
+<v 철수>annyeong <00:01.500><c.노래>chingu</c> &amp; neo</v>
+";
+
+        let vtt = Subtitles::new(SubtitleFormat::WebVtt);
+        assert_eq!(vtt.romanize(input), expected);
+    }
+
+    #[test]
+    fn test_lrc() {
+        let input = "\
+[ti:밤편지]
+[ar:아이유]
+[00:12.00][01:12.00]이 밤 그날의
+[00:15.30]<00:15.30>반딧불을 <00:16.10>당신의
+";
+
+        let lrc = Subtitles::new(SubtitleFormat::Lrc);
+        assert_eq!(
+            lrc.romanize(input),
+            "\
+[ti:밤편지]
+[ar:아이유]
+[00:12.00][01:12.00]i bam geunarui
+[00:15.30]<00:15.30>banditbureul <00:16.10>dangsinui
+"
+        );
+
+        let lrc = lrc.pronounce(true).dual(true);
+        assert_eq!(
+            lrc.romanize("[00:15.30]<00:15.30>반딧불을 <00:16.10>당신의"),
+            "[00:15.30]<00:15.30>반딧불을 <00:16.10>당신의\n[00:15.30]<00:15.30>반딛부를 <00:16.10>당시늬"
+        );
+
+        let lrc = Subtitles::new(SubtitleFormat::Lrc).dual(true);
+        assert_eq!(
+            lrc.romanize("[00:12.00]이 밤\r\n[00:15.30]당신의"),
+            "[00:12.00]이 밤\r\n[00:12.00]i bam\r\n[00:15.30]당신의\r\n[00:15.30]dangsinui"
+        );
+    }
+}
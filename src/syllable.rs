@@ -1,53 +1,47 @@
-use std::char;
+use std::convert::TryFrom;
+
+use crate::jamo::to_char;
+use crate::{Choseong, Error, Jongseong, Jungseong};
 
 // @see http://www.unicode.org/charts/PDF/UAC00.pdf
 // @see http://www.w3c.or.kr/i18n/hangul-i18n/ko-code.html
 const SYLLABLES_OFFSET: usize = 0xac00;
 const SYLLABLES_LEN: usize = 19 * 21 * 28;
 
-// @see https://www.unicode.org/charts/PDF/U1100.pdf
-const CHOSEONGS_OFFSET: usize = 0x1100;
-const CHOSEONGS_LEN: usize = 19;
-static CHOSEONGS: [char; CHOSEONGS_LEN] = [
-    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ',
-    'ㅉ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
-];
-
-const JUNGSEONGS_OFFSET: usize = 0x1161;
-const JUNGSEONGS_LEN: usize = 21;
-static JUNGSEONGS: [char; JUNGSEONGS_LEN] = [
-    'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅘ', 'ㅙ', 'ㅚ', 'ㅛ',
-    'ㅜ', 'ㅝ', 'ㅞ', 'ㅟ', 'ㅠ', 'ㅡ', 'ㅢ', 'ㅣ',
-];
-
-const JONGSEONGS_OFFSET: usize = 0x11a8;
-const JONGSEONGS_LEN: usize = 27;
-static JONGSEONGS: [char; JONGSEONGS_LEN] = [
-    'ㄱ', 'ㄲ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ',
-    'ㄿ', 'ㅀ', 'ㅁ', 'ㅂ', 'ㅄ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ',
-    'ㅎ',
-];
-
-#[derive(Debug, Eq, PartialEq)]
-pub enum Error {
-    Indecomposable,
-    InvalidSyllable,
-}
-
 /// A Hangeul syllable
 ///
-/// A syllable is any precomposed cluster of Hangeul jamos (letters). 11184
-/// characters are defined in [the 0xAC00-0xD7AF Unicode range][UAC00.pdf].
+/// A syllable is any precomposed cluster of Hangeul jamos (letters). 11172
+/// characters are defined in [the 0xAC00-0xD7A3 Unicode range][UAC00.pdf].
 ///
 /// An instance of `Syllable` is guaranteed to be composed of valid jamos.
+/// Syllables are ordered as in a dictionary, which is also the order of
+/// their code points.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// use hangeul::Syllable;
+///
+/// let s = Syllable::try_from('각').unwrap();
+/// assert_eq!(char::from(s), '각');
+/// assert!(Syllable::try_from('a').is_err());
+///
+/// assert!(s < Syllable::try_from('간').unwrap());
+/// assert!(s > Syllable::try_from('가').unwrap());
+/// ```
 ///
 /// [UAC00.pdf]: http://www.unicode.org/charts/PDF/UAC00.pdf
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub struct Syllable(char, char, Option<char>);
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Syllable(Choseong, Jungseong, Option<Jongseong>);
 
 impl Syllable {
     /// Creates a syllable from the decomposed jamos of a syllable.
     ///
+    /// The jamos are compatibility jamos (e.g., ㄱ). This returns `None` if
+    /// any of them is not valid in its position.
+    ///
     /// # Examples
     ///
     /// ```
@@ -56,26 +50,19 @@ impl Syllable {
     /// assert_eq!(Syllable::compose('ㄱ', 'ㅣ', Some('ㅁ')), Some('김'));
     /// assert_eq!(Syllable::compose('ㅅ', 'ㅔ', None), Some('세'));
     /// assert_eq!(Syllable::compose('ㅈ', 'ㅓ', Some('ㅇ')), Some('정'));
+    /// assert_eq!(Syllable::compose('ㄱ', 'ㅣ', Some('ㄸ')), None);
     /// assert_eq!(Syllable::compose('a', 'b', Some('c')), None);
     /// ```
     pub fn compose(j: char, k: char, m: Option<char>) -> Option<char> {
-        let ij = CHOSEONGS.iter().position(|&n| n == j);
-        let ik = JUNGSEONGS.iter().position(|&n| n == k);
-
-        if let Some(ij) = ij {
-            if let Some(ik) = ik {
-                let im = m
-                    .and_then(|m| JONGSEONGS.iter().position(|&n| n == m))
-                    .map(|i| i + 1)
-                    .unwrap_or(0);
+        let j = Choseong::from_compat(j)?;
+        let k = Jungseong::from_compat(k)?;
 
-                let c = SYLLABLES_OFFSET + (ij * 21 * 28) + (ik * 28) + im;
+        let m = match m {
+            Some(m) => Some(Jongseong::from_compat(m)?),
+            None => None,
+        };
 
-                return char::from_u32(c as u32);
-            }
-        }
-
-        None
+        Some(Syllable::new(j, k, m).as_char())
     }
 
     /// Validates whether a character is a Hangeul syllable (0xac00-0xd7a3).
@@ -90,17 +77,17 @@ impl Syllable {
     /// assert!(!Syllable::is_valid('a'));
     /// assert!(!Syllable::is_valid('あ'));
     /// ```
-    pub fn is_valid(s: char) -> bool {
+    pub const fn is_valid(s: char) -> bool {
         let u = s as usize;
         is_between(u, SYLLABLES_OFFSET, SYLLABLES_OFFSET + SYLLABLES_LEN)
     }
 
-    /// Wraps a Hangeul character for decomposition.
+    /// Decomposes a Hangeul character.
     ///
     /// # Errors
     ///
-    /// Returns `Error::InvalidSyllable` if the given character is not a valid
-    /// Hangeul syllable (0xac00-0xd7a3).
+    /// Returns `Error::InvalidSyllable` at position 0 if the given character
+    /// is not a valid Hangeul syllable (0xac00-0xd7a3).
     ///
     /// # Examples
     ///
@@ -112,149 +99,111 @@ impl Syllable {
     /// assert!(Syllable::from_char('a').is_err());
     /// assert!(Syllable::from_char('あ').is_err());
     /// ```
-    pub fn from_char(c: char) -> Result<Syllable, Error> {
-        let j = extract_choseong(c)?;
-        let k = extract_jungseong(c)?;
-        let m = extract_jongseong(c)?;
-        Ok(Syllable::new(j, k, m))
+    pub const fn from_char(c: char) -> Result<Syllable, Error> {
+        if !Syllable::is_valid(c) {
+            return Err(Error::InvalidSyllable {
+                position: 0,
+                character: c,
+            });
+        }
+
+        let u = c as usize - SYLLABLES_OFFSET;
+
+        let j = Choseong::ALL[u / (21 * 28)];
+        let k = Jungseong::ALL[(u % (21 * 28)) / 28];
+
+        let m = match u % 28 {
+            0 => None,
+            i => Jongseong::from_index(i - 1),
+        };
+
+        Ok(Syllable(j, k, m))
     }
 
     /// Creates a new syllable from jamos.
     ///
-    /// Note this does not validate the resulting `Syllable`.
-    ///
     /// # Examples
     ///
     /// ```
-    /// use hangeul::Syllable;
+    /// use hangeul::{Choseong, Jongseong, Jungseong, Syllable};
     ///
-    /// let s = Syllable::new('ㅇ', 'ㅣ', None);
-    /// assert_eq!(s.choseong(), 'ㅇ');
-    /// assert_eq!(s.jungseong(), 'ㅣ');
+    /// let s = Syllable::new(Choseong::Ieung, Jungseong::I, None);
+    /// assert_eq!(s.choseong(), Choseong::Ieung);
+    /// assert_eq!(s.jungseong(), Jungseong::I);
     /// assert_eq!(s.jongseong(), None);
+    /// assert_eq!(s.as_char(), '이');
     ///
-    /// let s = Syllable::new('ㄷ', 'ㅏ', Some('ㄹ'));
-    /// assert_eq!(s.choseong(), 'ㄷ');
-    /// assert_eq!(s.jungseong(), 'ㅏ');
-    /// assert_eq!(s.jongseong(), Some('ㄹ'));
+    /// let s = Syllable::new(Choseong::Digeut, Jungseong::A, Some(Jongseong::Rieul));
+    /// assert_eq!(s.choseong(), Choseong::Digeut);
+    /// assert_eq!(s.jungseong(), Jungseong::A);
+    /// assert_eq!(s.jongseong(), Some(Jongseong::Rieul));
+    /// assert_eq!(s.as_char(), '달');
     /// ```
-
-    pub fn new(j: char, k: char, m: Option<char>) -> Syllable {
+    pub const fn new(j: Choseong, k: Jungseong, m: Option<Jongseong>) -> Syllable {
         Syllable(j, k, m)
     }
 
-    pub fn choseong(&self) -> char {
+    pub const fn choseong(&self) -> Choseong {
         self.0
     }
 
     /// Sets the initial consonant.
-    ///
-    /// # Panics
-    ///
-    /// Panics when the given character is not a valid choseong.
-    pub fn set_choseong(&mut self, c: char) {
-        let u = c as usize;
-
-        if is_between(u, CHOSEONGS_OFFSET, CHOSEONGS_OFFSET + CHOSEONGS_LEN) {
-            panic!("invalid choseong '{}'", c);
-        }
-
-        self.0 = c;
+    pub fn set_choseong(&mut self, j: Choseong) {
+        self.0 = j;
     }
 
-    pub fn jungseong(&self) -> char {
+    pub const fn jungseong(&self) -> Jungseong {
         self.1
     }
 
     /// Sets the medial vowel.
-    ///
-    /// # Panics
-    ///
-    /// Panics when the given character is not a valid jungseong.
-    pub fn set_jungseong(&mut self, c: char) {
-        let u = c as usize;
-
-        if is_between(u, JUNGSEONGS_OFFSET, JUNGSEONGS_OFFSET + JUNGSEONGS_LEN) {
-            panic!("invalid junseong '{}'", c);
-        }
-
-        self.1 = c;
+    pub fn set_jungseong(&mut self, k: Jungseong) {
+        self.1 = k;
     }
 
-    pub fn jongseong(&self) -> Option<char> {
+    pub const fn jongseong(&self) -> Option<Jongseong> {
         self.2
     }
 
     /// Sets the optional final consonant.
-    ///
-    /// # Panics
-    ///
-    /// Panics when the given character is not a valid jongseong.
-    pub fn set_jongseong(&mut self, c: Option<char>) {
-        if let Some(d) = c {
-            let u = d as usize;
-
-            if is_between(u, JONGSEONGS_OFFSET, JONGSEONGS_OFFSET + JONGSEONGS_LEN) {
-                panic!("invalid jonseong '{}'", d);
-            }
-        }
-
-        self.2 = c;
+    pub fn set_jongseong(&mut self, m: Option<Jongseong>) {
+        self.2 = m;
     }
 
-    pub fn decompose(&self) -> (char, char, Option<char>) {
-        (self.choseong(), self.jungseong(), self.jongseong())
+    pub const fn decompose(&self) -> (Choseong, Jungseong, Option<Jongseong>) {
+        (self.0, self.1, self.2)
     }
 
-    pub fn as_char(&self) -> char {
-        Syllable::compose(self.choseong(), self.jungseong(), self.jongseong()).unwrap()
-    }
-}
+    /// Composes the syllable into a Hangeul character.
+    pub const fn as_char(&self) -> char {
+        let m = match self.2 {
+            Some(m) => m as usize + 1,
+            None => 0,
+        };
 
-/// Returns whether `n` is in [`lower`, `upper`).
-fn is_between(n: usize, lower: usize, upper: usize) -> bool {
-    n >= lower && n < upper
-}
+        let u = SYLLABLES_OFFSET + (self.0 as usize * 21 * 28) + (self.1 as usize * 28) + m;
 
-fn extract_choseong(s: char) -> Result<char, Error> {
-    if !Syllable::is_valid(s) {
-        return Err(Error::InvalidSyllable);
+        to_char(u as u32)
     }
-
-    let u = s as usize;
-    let i = (u - SYLLABLES_OFFSET) / (21 * 28);
-    CHOSEONGS
-        .get(i)
-        .map_or(Err(Error::Indecomposable), |&c| Ok(c))
 }
 
-fn extract_jungseong(s: char) -> Result<char, Error> {
-    if !Syllable::is_valid(s) {
-        return Err(Error::InvalidSyllable);
-    }
+impl TryFrom<char> for Syllable {
+    type Error = Error;
 
-    let u = s as usize;
-    let i = ((u - SYLLABLES_OFFSET) % (21 * 28)) / 28;
-    JUNGSEONGS
-        .get(i)
-        .map_or(Err(Error::Indecomposable), |&c| Ok(c))
+    fn try_from(c: char) -> Result<Syllable, Error> {
+        Syllable::from_char(c)
+    }
 }
 
-fn extract_jongseong(s: char) -> Result<Option<char>, Error> {
-    if !Syllable::is_valid(s) {
-        return Err(Error::InvalidSyllable);
+impl From<Syllable> for char {
+    fn from(s: Syllable) -> char {
+        s.as_char()
     }
+}
 
-    let u = s as isize;
-    let i = (u - SYLLABLES_OFFSET as isize) % 28 - 1;
-
-    if i >= 0 {
-        JONGSEONGS
-            .get(i as usize)
-            .map_or(Err(Error::Indecomposable), |&c| Ok(Some(c)))
-    } else {
-        Ok(None)
-    }
+/// Returns whether `n` is in [`lower`, `upper`).
+const fn is_between(n: usize, lower: usize, upper: usize) -> bool {
+    n >= lower && n < upper
 }
 
 #[cfg(test)]
@@ -272,29 +221,54 @@ mod tests {
     }
 
     #[test]
-    fn test_extract_choseong() {
-        assert_eq!(extract_choseong('김'), Ok('ㄱ'));
-        assert_eq!(extract_choseong('세'), Ok('ㅅ'));
-        assert_eq!(extract_choseong('정'), Ok('ㅈ'));
-
-        assert!(extract_choseong('a').is_err());
+    fn test_from_char() {
+        let s = Syllable::from_char('김').unwrap();
+        assert_eq!(
+            s.decompose(),
+            (Choseong::Giyeok, Jungseong::I, Some(Jongseong::Mieum))
+        );
+
+        let s = Syllable::from_char('세').unwrap();
+        assert_eq!(s.decompose(), (Choseong::Siot, Jungseong::E, None));
+
+        let s = Syllable::from_char('정').unwrap();
+        assert_eq!(
+            s.decompose(),
+            (Choseong::Jieut, Jungseong::Eo, Some(Jongseong::Ieung))
+        );
+
+        assert_eq!(
+            Syllable::from_char('a'),
+            Err(Error::InvalidSyllable {
+                position: 0,
+                character: 'a'
+            })
+        );
+        assert!(Syllable::from_char('\u{d7a4}').is_err());
     }
 
     #[test]
-    fn test_extract_jungseong() {
-        assert_eq!(extract_jungseong('김'), Ok('ㅣ'));
-        assert_eq!(extract_jungseong('세'), Ok('ㅔ'));
-        assert_eq!(extract_jungseong('정'), Ok('ㅓ'));
-
-        assert!(extract_jungseong('a').is_err());
+    fn test_as_char() {
+        for u in 0xac00..=0xd7a3 {
+            let c = std::char::from_u32(u).unwrap();
+            let s = Syllable::from_char(c).unwrap();
+            assert_eq!(s.as_char(), c);
+
+            let (j, k, m) = s.decompose();
+            let m = m.map(Jongseong::to_compat);
+            assert_eq!(Syllable::compose(j.to_compat(), k.to_compat(), m), Some(c));
+        }
     }
 
     #[test]
-    fn test_extract_jongseong() {
-        assert_eq!(extract_jongseong('김'), Ok(Some('ㅁ')));
-        assert_eq!(extract_jongseong('세'), Ok(None));
-        assert_eq!(extract_jongseong('정'), Ok(Some('ㅇ')));
-
-        assert!(extract_jongseong('a').is_err());
+    fn test_ord() {
+        let mut prev = None;
+
+        for u in 0xac00..=0xd7a3 {
+            let c = std::char::from_u32(u).unwrap();
+            let s = Syllable::try_from(c).unwrap();
+            assert!(prev < Some(s));
+            prev = Some(s);
+        }
     }
 }
@@ -0,0 +1,68 @@
+use crate::pronunciation::Pronouncer;
+use crate::Syllable;
+
+/// Information about the word being transcribed.
+#[derive(Clone, Copy, Debug)]
+pub struct Context<'a> {
+    spelling: &'a [Syllable],
+}
+
+impl<'a> Context<'a> {
+    pub fn new(spelling: &'a [Syllable]) -> Context<'a> {
+        Context { spelling }
+    }
+
+    /// Returns the syllables of the word as written, i.e., before any
+    /// pronunciation rules were applied.
+    pub fn spelling(&self) -> &'a [Syllable] {
+        self.spelling
+    }
+}
+
+/// A romanization system.
+///
+/// A transcriber converts the syllables of a single word to its target
+/// script. Text is split into words by [`Lexer`](crate::Lexer), and each
+/// word is optionally passed through a [`Pronouncer`] first, so an
+/// implementation only has to handle the syllables themselves.
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::{romanize_with, Context, Pronouncer, Syllable, Transcriber};
+///
+/// /// Spells each syllable by its code point.
+/// struct CodePoints;
+///
+/// impl Transcriber for CodePoints {
+///     fn pronouncer(&self) -> Option<Pronouncer> {
+///         None
+///     }
+///
+///     fn transcribe(&self, syllables: &[Syllable], _: &Context) -> String {
+///         syllables
+///             .iter()
+///             .map(|s| format!("<{:X}>", s.as_char() as u32))
+///             .collect()
+///     }
+/// }
+///
+/// assert_eq!(romanize_with("가 나", &CodePoints), "<AC00> <B098>");
+/// ```
+pub trait Transcriber {
+    /// Returns the pronouncer applied to each word before transcription.
+    ///
+    /// Transcription systems based on standard pronunciation use the
+    /// default. Transliteration systems, which follow spelling, return
+    /// `None`.
+    fn pronouncer(&self) -> Option<Pronouncer> {
+        Some(Pronouncer::new(false))
+    }
+
+    /// Transcribes the syllables of a single word.
+    ///
+    /// When `pronouncer` returns a pronouncer, `syllables` is the
+    /// pronunciation of the word. The original spelling is always available
+    /// through `ctx`.
+    fn transcribe(&self, syllables: &[Syllable], ctx: &Context) -> String;
+}
@@ -1,57 +1,344 @@
-use crate::pronunciation::Pronouncer;
-use crate::revised_romanization::transcribe;
-use crate::Syllable;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::slice;
+use std::str::FromStr;
+use std::vec;
+
+use crate::{
+    Context, Error, PhonemicSyllable, Pronouncer, RevisedRomanization, Syllable, Transcriber,
+};
 
 /// A word composed of Hangeul syllables
-#[derive(Debug)]
-pub struct Word(String);
+///
+/// A word holds its decoded syllables, so the pronunciation rules and
+/// transcribers work on them directly, along with their text. Words are
+/// ordered as in a dictionary.
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::{Syllable, Word};
+///
+/// let word: Word = "사랑".parse().unwrap();
+/// assert_eq!(word.to_string(), "사랑");
+/// assert_eq!(word.as_ref(), "사랑");
+///
+/// let chars: String = word.into_iter().map(char::from).collect();
+/// assert_eq!(chars, "사랑");
+///
+/// let mut words: Vec<Word> = ["하늘", "바다", "바람"]
+///     .iter()
+///     .map(|s| s.parse().unwrap())
+///     .collect();
+/// words.sort();
+/// assert_eq!(words[0].as_ref(), "바다");
+/// assert_eq!(words[1].as_ref(), "바람");
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Word {
+    syllables: Vec<Syllable>,
+    text: String,
+}
 
 impl Word {
-    /// Wraps a string of Hangeul characters.
+    /// Decodes a string of Hangeul characters.
     ///
-    /// Strings must only contain Hangeul syllables.
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidSyllable` with the byte offset of the first
+    /// character that is not a Hangeul syllable.
     ///
     /// # Examples
     ///
     /// ```
-    /// use hangeul::Word;
-    /// let _ = Word::new("몰라요");
+    /// use hangeul::{Error, Word};
+    ///
+    /// assert!(Word::new("몰라요").is_ok());
+    /// assert_eq!(
+    ///     Word::new("몰라a"),
+    ///     Err(Error::InvalidSyllable { position: 6, character: 'a' })
+    /// );
     /// ```
-    pub fn new<S>(s: S) -> Word
+    pub fn new<S>(s: S) -> Result<Word, Error>
     where
-        S: Into<String>,
+        S: AsRef<str>,
     {
-        Word(s.into())
+        let text = s.as_ref();
+
+        let syllables = text
+            .char_indices()
+            .map(|(i, c)| {
+                Syllable::from_char(c).map_err(|_| Error::InvalidSyllable {
+                    position: i,
+                    character: c,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Word {
+            syllables,
+            text: text.to_string(),
+        })
     }
 
-    /// Returns a list of syllables that make up the word.
+    /// Returns the syllables that make up the word.
     ///
     /// # Examples
     ///
     /// ```
     /// use hangeul::{Syllable, Word};
     ///
-    /// let word = Word::new("좋아요");
-    /// assert_eq!(word.syllables(), vec![
+    /// let word = Word::new("좋아요").unwrap();
+    /// assert_eq!(word.syllables(), [
     ///     Syllable::from_char('좋').unwrap(),
     ///     Syllable::from_char('아').unwrap(),
     ///     Syllable::from_char('요').unwrap(),
     /// ]);
     /// ```
-    pub fn syllables(&self) -> Vec<Syllable> {
-        self.0
-            .chars()
-            .filter_map(|c| Syllable::from_char(c).ok())
-            .collect()
+    pub fn syllables(&self) -> &[Syllable] {
+        &self.syllables
+    }
+
+    /// Returns the syllables that make up the word for modification.
+    ///
+    /// The text of the word is updated when the returned view is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hangeul::{Jongseong, Word};
+    ///
+    /// let mut word = Word::new("감").unwrap();
+    /// word.syllables_mut()[0].set_jongseong(Some(Jongseong::Nieun));
+    /// assert_eq!(word.as_ref(), "간");
+    /// ```
+    pub fn syllables_mut(&mut self) -> SyllablesMut<'_> {
+        SyllablesMut { word: self }
     }
 
+    /// Romanizes the word using Revised Romanization rules.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hangeul::Word;
+    /// assert_eq!(Word::new("한국어").unwrap().romanize(), "hangugeo");
+    /// ```
     pub fn romanize(&self) -> String {
-        let pronouncer = Pronouncer::new(false);
-        let transformed_word = pronouncer.pronounce(self);
-        transcribe(&transformed_word)
+        self.romanize_with(&RevisedRomanization)
     }
 
-    pub fn to_string(&self) -> String {
-        self.0.clone()
+    /// Romanizes the word using the given transcriber.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hangeul::{Word, Yale};
+    /// assert_eq!(Word::new("한국어").unwrap().romanize_with(&Yale::new()), "hankwuk.e");
+    /// ```
+    pub fn romanize_with<T>(&self, transcriber: &T) -> String
+    where
+        T: Transcriber + ?Sized,
+    {
+        let ctx = Context::new(&self.syllables);
+
+        match transcriber.pronouncer() {
+            Some(pronouncer) => {
+                let mut pronunciation = self.clone();
+                pronouncer.pronounce_in_place(&mut pronunciation);
+                transcriber.transcribe(&pronunciation.syllables, &ctx)
+            }
+            None => transcriber.transcribe(&self.syllables, &ctx),
+        }
+    }
+
+    /// Romanizes each syllable of the word using Revised Romanization rules.
+    ///
+    /// See [`Word::romanize_syllables_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hangeul::Word;
+    ///
+    /// let pieces: Vec<_> = Word::new("한국어")
+    ///     .unwrap()
+    ///     .romanize_syllables()
+    ///     .into_iter()
+    ///     .map(|(_, _, piece)| piece)
+    ///     .collect();
+    ///
+    /// assert_eq!(pieces, ["han", "gu", "geo"]);
+    /// ```
+    pub fn romanize_syllables(&self) -> Vec<(Syllable, Syllable, String)> {
+        self.romanize_syllables_with(&RevisedRomanization)
+    }
+
+    /// Romanizes each syllable of the word using the given transcriber.
+    ///
+    /// This returns the original syllable, the pronounced syllable, and the
+    /// romanized piece for each syllable. The pronounced syllable is the
+    /// same as the original when the transcriber has no pronouncer.
+    ///
+    /// A piece is what the transcriber writes for the word up to and
+    /// including the syllable, less what it writes for the syllables before
+    /// it. When the former is not a prefix of the whole romanization, the
+    /// piece is left empty and its text is carried by the next piece. The
+    /// pieces always concatenate to [`Word::romanize_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hangeul::{Syllable, Word};
+    ///
+    /// let syllables = Word::new("신라").unwrap().romanize_syllables();
+    ///
+    /// assert_eq!(syllables[0].0, Syllable::from_char('신').unwrap());
+    /// assert_eq!(syllables[0].1, Syllable::from_char('실').unwrap());
+    /// assert_eq!(syllables[0].2, "sil");
+    /// assert_eq!(syllables[1].2, "la");
+    /// ```
+    pub fn romanize_syllables_with<T>(&self, transcriber: &T) -> Vec<(Syllable, Syllable, String)>
+    where
+        T: Transcriber + ?Sized,
+    {
+        let spelling = &self.syllables;
+        let ctx = Context::new(spelling);
+
+        let mut pronunciation = self.clone();
+
+        if let Some(pronouncer) = transcriber.pronouncer() {
+            pronouncer.pronounce_in_place(&mut pronunciation);
+        }
+
+        let syllables = &pronunciation.syllables;
+
+        let full = transcriber.transcribe(syllables, &ctx);
+
+        let mut res = Vec::with_capacity(syllables.len());
+        let mut start = 0;
+
+        for i in 1..=syllables.len() {
+            let end = if i == syllables.len() {
+                full.len()
+            } else {
+                let prefix = transcriber.transcribe(&syllables[..i], &ctx);
+
+                if prefix.len() >= start && full.starts_with(&prefix) {
+                    prefix.len()
+                } else {
+                    start
+                }
+            };
+
+            res.push((
+                spelling[i - 1],
+                syllables[i - 1],
+                full[start..end].to_string(),
+            ));
+            start = end;
+        }
+
+        res
+    }
+
+    /// Returns the phonemes of the pronunciation of the word used by
+    /// [`Ipa`](crate::Ipa), by syllable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hangeul::{Phoneme, Word};
+    ///
+    /// let syllables = Word::new("국물").unwrap().phonemes();
+    /// assert_eq!(syllables.len(), 2);
+    /// assert_eq!(syllables[0].coda(), Some(Phoneme::Ng));
+    /// ```
+    pub fn phonemes(&self) -> Vec<PhonemicSyllable> {
+        Pronouncer::phonetic()
+            .pronounce(self)
+            .syllables
+            .into_iter()
+            .map(PhonemicSyllable::from)
+            .collect()
+    }
+
+    /// Writes the syllables back to the text.
+    fn encode(&mut self) {
+        self.text.clear();
+        self.text
+            .extend(self.syllables.iter().map(|&s| char::from(s)));
+    }
+}
+
+impl From<Vec<Syllable>> for Word {
+    fn from(syllables: Vec<Syllable>) -> Word {
+        let text = syllables.iter().map(|&s| char::from(s)).collect();
+        Word { syllables, text }
+    }
+}
+
+impl FromStr for Word {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Word, Error> {
+        Word::new(s)
+    }
+}
+
+impl fmt::Display for Word {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+impl AsRef<str> for Word {
+    fn as_ref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl IntoIterator for Word {
+    type Item = Syllable;
+    type IntoIter = vec::IntoIter<Syllable>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.syllables.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Word {
+    type Item = &'a Syllable;
+    type IntoIter = slice::Iter<'a, Syllable>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.syllables.iter()
+    }
+}
+
+/// A mutable view of the syllables of a [`Word`]
+///
+/// This is returned by [`Word::syllables_mut`].
+pub struct SyllablesMut<'a> {
+    word: &'a mut Word,
+}
+
+impl Deref for SyllablesMut<'_> {
+    type Target = [Syllable];
+
+    fn deref(&self) -> &[Syllable] {
+        &self.word.syllables
+    }
+}
+
+impl DerefMut for SyllablesMut<'_> {
+    fn deref_mut(&mut self) -> &mut [Syllable] {
+        &mut self.word.syllables
+    }
+}
+
+impl Drop for SyllablesMut<'_> {
+    fn drop(&mut self) {
+        self.word.encode();
     }
 }
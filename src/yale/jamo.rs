@@ -0,0 +1,107 @@
+use crate::{Choseong, Jongseong, Jungseong};
+
+/// Spellings of all initial consonants other than the silent ㅇ.
+pub static INITIAL_CONSONANTS: [&str; 18] = [
+    "k", "kk", "n", "t", "tt", "l", "m", "p", "pp", "s", "ss", "c", "cc", "ch", "kh", "th", "ph",
+    "h",
+];
+
+/// Spellings of all final consonants.
+pub static FINAL_CONSONANTS: [&str; 27] = [
+    "k", "kk", "ks", "n", "nc", "nh", "t", "l", "lk", "lm", "lp", "ls", "lth", "lph", "lh", "m",
+    "p", "ps", "s", "ss", "ng", "c", "ch", "kh", "th", "ph", "h",
+];
+
+pub fn transliterate_initial_consonant(j: Choseong) -> &'static str {
+    match j {
+        Choseong::Giyeok => "k",
+        Choseong::SsangGiyeok => "kk",
+        Choseong::Nieun => "n",
+        Choseong::Digeut => "t",
+        Choseong::SsangDigeut => "tt",
+        Choseong::Rieul => "l",
+        Choseong::Mieum => "m",
+        Choseong::Bieup => "p",
+        Choseong::SsangBieup => "pp",
+        Choseong::Siot => "s",
+        Choseong::SsangSiot => "ss",
+        Choseong::Ieung => "",
+        Choseong::Jieut => "c",
+        Choseong::SsangJieut => "cc",
+        Choseong::Chieut => "ch",
+        Choseong::Kieuk => "kh",
+        Choseong::Tieut => "th",
+        Choseong::Pieup => "ph",
+        Choseong::Hieut => "h",
+    }
+}
+
+/// Transliterates a vowel.
+///
+/// In modern Korean, ㅜ loses its w after a bilabial (mul, pul). When
+/// `middle_korean` is set, this simplification is not made, and ㅗ is
+/// written wo, freeing o for the obsolete ㆍ.
+pub fn transliterate_medial_vowel(
+    j: Jungseong,
+    bilabial: bool,
+    middle_korean: bool,
+) -> &'static str {
+    match j {
+        Jungseong::A => "a",
+        Jungseong::Ae => "ay",
+        Jungseong::Ya => "ya",
+        Jungseong::Yae => "yay",
+        Jungseong::Eo => "e",
+        Jungseong::E => "ey",
+        Jungseong::Yeo => "ye",
+        Jungseong::Ye => "yey",
+        Jungseong::O if middle_korean => "wo",
+        Jungseong::O => "o",
+        Jungseong::Wa => "wa",
+        Jungseong::Wae => "way",
+        Jungseong::Oe if middle_korean => "woy",
+        Jungseong::Oe => "oy",
+        Jungseong::Yo => "yo",
+        Jungseong::U if bilabial && !middle_korean => "u",
+        Jungseong::U => "wu",
+        Jungseong::Wo => "we",
+        Jungseong::We => "wey",
+        Jungseong::Wi => "wi",
+        Jungseong::Yu => "yu",
+        Jungseong::Eu => "u",
+        Jungseong::Ui => "uy",
+        Jungseong::I => "i",
+    }
+}
+
+pub fn transliterate_final_consonant(j: Jongseong) -> &'static str {
+    match j {
+        Jongseong::Giyeok => "k",
+        Jongseong::SsangGiyeok => "kk",
+        Jongseong::GiyeokSiot => "ks",
+        Jongseong::Nieun => "n",
+        Jongseong::NieunJieut => "nc",
+        Jongseong::NieunHieut => "nh",
+        Jongseong::Digeut => "t",
+        Jongseong::Rieul => "l",
+        Jongseong::RieulGiyeok => "lk",
+        Jongseong::RieulMieum => "lm",
+        Jongseong::RieulBieup => "lp",
+        Jongseong::RieulSiot => "ls",
+        Jongseong::RieulTieut => "lth",
+        Jongseong::RieulPieup => "lph",
+        Jongseong::RieulHieut => "lh",
+        Jongseong::Mieum => "m",
+        Jongseong::Bieup => "p",
+        Jongseong::BieupSiot => "ps",
+        Jongseong::Siot => "s",
+        Jongseong::SsangSiot => "ss",
+        Jongseong::Ieung => "ng",
+        Jongseong::Jieut => "c",
+        Jongseong::Chieut => "ch",
+        Jongseong::Kieuk => "kh",
+        Jongseong::Tieut => "th",
+        Jongseong::Pieup => "ph",
+        Jongseong::Hieut => "h",
+    }
+}
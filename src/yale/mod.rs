@@ -0,0 +1,243 @@
+mod jamo;
+
+use self::jamo::*;
+use crate::{Choseong, Context, Jungseong, Pronouncer, Syllable, Transcriber};
+
+/// Yale romanization
+///
+/// Yale is a morphophonemic transliteration: it follows the spelling of a
+/// word rather than its pronunciation, so no pronunciation rules are applied
+/// and final consonant clusters are written out in full (국물 => kwukmul,
+/// 없어 => eps.e). Notably, ㅓ is written e, ㅡ is u, and ㅜ is wu.
+///
+/// Where a syllable boundary would otherwise be ambiguous, it is marked with
+/// a period, e.g., before a vowel following a final consonant. Periods can
+/// be disabled.
+///
+/// The Middle Korean extension writes ㅗ as wo and keeps ㅜ as wu after
+/// bilabials. Obsolete letters (e.g., ㆍ, ㅿ) cannot be represented by
+/// precomposed syllables and are therefore not transliterated.
+///
+/// # Examples
+///
+/// ```
+/// use hangeul::Yale;
+///
+/// let yale = Yale::new();
+/// assert_eq!(yale.romanize("국물"), "kwukmul");
+/// assert_eq!(yale.romanize("없어"), "eps.e");
+///
+/// let yale = Yale::new().periods(false);
+/// assert_eq!(yale.romanize("없어"), "epse");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Yale {
+    periods: bool,
+    middle_korean: bool,
+}
+
+impl Yale {
+    /// Creates a Yale transcriber for modern Korean, with periods enabled.
+    pub fn new() -> Yale {
+        Yale {
+            periods: true,
+            middle_korean: false,
+        }
+    }
+
+    /// Sets whether ambiguous syllable boundaries are marked with a period.
+    pub fn periods(mut self, periods: bool) -> Yale {
+        self.periods = periods;
+        self
+    }
+
+    /// Sets whether to use the Middle Korean vowel conventions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hangeul::Yale;
+    ///
+    /// let yale = Yale::new().middle_korean(true);
+    /// assert_eq!(yale.romanize("소리"), "swoli");
+    /// assert_eq!(yale.romanize("물"), "mwul");
+    /// ```
+    pub fn middle_korean(mut self, middle_korean: bool) -> Yale {
+        self.middle_korean = middle_korean;
+        self
+    }
+
+    /// Romanizes text using Yale rules.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hangeul::Yale;
+    ///
+    /// let yale = Yale::new();
+    /// assert_eq!(yale.romanize("한국어"), "hankwuk.e");
+    /// assert_eq!(yale.romanize("BTS - 봄날"), "BTS - pomnal");
+    /// ```
+    pub fn romanize(&self, input: &str) -> String {
+        crate::romanize_with(input, self)
+    }
+
+    fn is_ambiguous_boundary(
+        &self,
+        (prev_vowel, prev_final): (&str, Option<&str>),
+        initial: &str,
+        vowel: &str,
+    ) -> bool {
+        match prev_final {
+            // The final consonant would be read as the next initial.
+            Some(_) if initial.is_empty() => true,
+            None if initial.is_empty() => {
+                let is_vowel = |s: &str| {
+                    Jungseong::ALL
+                        .iter()
+                        .any(|&k| transliterate_medial_vowel(k, false, self.middle_korean) == s)
+                };
+
+                is_resplittable(prev_vowel, vowel, is_vowel, is_vowel)
+            }
+            // The consonants could be split differently, including with no
+            // final consonant (박하 => "pak.ha", 바카 => "pa.kha"). A final
+            // followed by no initial is always marked, so the next syllable
+            // must keep an initial.
+            _ => is_resplittable(
+                prev_final.unwrap_or(""),
+                initial,
+                |s| s.is_empty() || FINAL_CONSONANTS.contains(&s),
+                |s| INITIAL_CONSONANTS.contains(&s),
+            ),
+        }
+    }
+}
+
+impl Transcriber for Yale {
+    fn pronouncer(&self) -> Option<Pronouncer> {
+        None
+    }
+
+    fn transcribe(&self, syllables: &[Syllable], _: &Context) -> String {
+        let mut res = String::new();
+
+        // The vowel and final consonant of the previous syllable.
+        let mut prev: Option<(&str, Option<&str>)> = None;
+
+        for s in syllables {
+            let (j, k, m) = s.decompose();
+
+            let initial = transliterate_initial_consonant(j);
+            let vowel = transliterate_medial_vowel(k, is_bilabial(j), self.middle_korean);
+            let fin = m.map(transliterate_final_consonant);
+
+            if self.periods {
+                if let Some(tail) = prev {
+                    if self.is_ambiguous_boundary(tail, initial, vowel) {
+                        res.push('.');
+                    }
+                }
+            }
+
+            res.push_str(initial);
+            res.push_str(vowel);
+
+            if let Some(fin) = fin {
+                res.push_str(fin);
+            }
+
+            prev = Some((vowel, fin));
+        }
+
+        res
+    }
+}
+
+impl Default for Yale {
+    fn default() -> Yale {
+        Yale::new()
+    }
+}
+
+fn is_bilabial(j: Choseong) -> bool {
+    matches!(
+        j,
+        Choseong::Mieum | Choseong::Bieup | Choseong::SsangBieup | Choseong::Pieup
+    )
+}
+
+/// Returns whether `left` + `right` can be split into a different pair of
+/// valid spellings.
+fn is_resplittable<F, G>(left: &str, right: &str, is_left: F, is_right: G) -> bool
+where
+    F: Fn(&str) -> bool,
+    G: Fn(&str) -> bool,
+{
+    let joined = format!("{}{}", left, right);
+
+    (0..=joined.len()).filter(|&i| i != left.len()).any(|i| {
+        let (a, b) = joined.split_at(i);
+        is_left(a) && is_right(b)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Yale;
+
+    fn r(s: &str) -> String {
+        Yale::new().romanize(s)
+    }
+
+    #[test]
+    fn test_vowels() {
+        assert_eq!(r("어머니"), "emeni");
+        assert_eq!(r("서울"), "sewul");
+        assert_eq!(r("그"), "ku");
+        assert_eq!(r("의사"), "uysa");
+        assert_eq!(r("부산"), "pusan");
+    }
+
+    #[test]
+    fn test_clusters() {
+        assert_eq!(r("닭"), "talk");
+        assert_eq!(r("값도"), "kapsto");
+        assert_eq!(r("않다"), "anhta");
+        assert_eq!(r("읊다"), "ulphta");
+        assert_eq!(r("국물"), "kwukmul");
+    }
+
+    #[test]
+    fn test_periods() {
+        assert_eq!(r("없어"), "eps.e");
+        assert_eq!(r("한국어"), "hankwuk.e");
+        assert_eq!(r("아이"), "ai");
+        assert_eq!(r("애아"), "ay.a");
+        assert_eq!(r("아예"), "a.yey");
+        assert_eq!(r("박하"), "pak.ha");
+        assert_eq!(r("박카"), "pak.kha");
+        assert_eq!(r("밖아"), "pakk.a");
+        assert_eq!(r("바다"), "pata");
+        assert_eq!(r("한국"), "hankwuk");
+    }
+
+    #[test]
+    fn test_periods_without_final_consonant() {
+        assert_eq!(r("바카"), "pa.kha");
+        assert_eq!(r("박하"), "pak.ha");
+        assert_ne!(r("바카"), r("박하"));
+
+        assert_eq!(r("다티"), "ta.thi");
+        assert_eq!(r("닫히"), "tat.hi");
+        assert_ne!(r("다티"), r("닫히"));
+    }
+
+    #[test]
+    fn test_middle_korean() {
+        let yale = Yale::new().middle_korean(true);
+        assert_eq!(yale.romanize("소리"), "swoli");
+        assert_eq!(yale.romanize("외"), "woy");
+        assert_eq!(yale.romanize("부텨"), "pwu.thye");
+    }
+}
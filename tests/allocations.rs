@@ -0,0 +1,60 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use hangeul::{romanize_into, Romanized};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// A fixed-capacity `fmt::Write` buffer.
+struct Buf {
+    data: [u8; 64],
+    len: usize,
+}
+
+impl Write for Buf {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let end = self.len + s.len();
+        self.data
+            .get_mut(self.len..end)
+            .ok_or(std::fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[test]
+fn test_ascii_is_not_allocated() {
+    let mut buf = Buf {
+        data: [0; 64],
+        len: 0,
+    };
+
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    romanize_into("MOMOLAND - BBoom BBoom (2018)", &mut buf).unwrap();
+    write!(buf, "{}", Romanized(" & more")).unwrap();
+    let after = ALLOCATIONS.load(Ordering::SeqCst);
+
+    assert_eq!(after - before, 0);
+    assert_eq!(
+        &buf.data[..buf.len],
+        &b"MOMOLAND - BBoom BBoom (2018) & more"[..]
+    );
+}
@@ -0,0 +1,201 @@
+//! Checks that no public operation panics, whatever the input.
+
+use hangeul::*;
+
+const SYLLABLES: std::ops::RangeInclusive<u32> = 0xac00..=0xd7a3;
+
+/// A deterministic xorshift generator, so failures are reproducible.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    /// Generates a string biased towards Hangeul, jamo, markup and
+    /// subtitle syntax, with the occasional arbitrary character.
+    fn string(&mut self, max_len: u64) -> String {
+        const SYNTAX: &[char] = &[
+            ' ', '\n', '\r', '<', '>', '/', '&', ';', '"', '\'', '=', '*', '_', '`', '[', ']', '(',
+            ')', ':', '.', ',', '-', '0', '1', '9', 'a', 'r', 't', 'W', '#', '!', '\u{feff}',
+        ];
+
+        let len = self.below(max_len + 1);
+
+        (0..len)
+            .map(|_| {
+                let n = match self.below(8) {
+                    0..=3 => self.below(11_172) as u32 + 0xac00,
+                    4 => self.below(0x5e) as u32 + 0x3131,
+                    5 => self.below(0x100) as u32 + 0x1100,
+                    6 => SYNTAX[self.below(SYNTAX.len() as u64) as usize] as u32,
+                    _ => self.below(0x11_0000) as u32,
+                };
+
+                std::char::from_u32(n).unwrap_or('\u{fffd}')
+            })
+            .collect()
+    }
+}
+
+fn all_chars() -> impl Iterator<Item = char> {
+    (0..=0x10_ffff).filter_map(std::char::from_u32)
+}
+
+fn all_syllables() -> impl Iterator<Item = char> {
+    SYLLABLES.filter_map(std::char::from_u32)
+}
+
+fn transcribers() -> Vec<Box<dyn Transcriber>> {
+    let scheme: Scheme = include_str!("../schemes/revised_romanization.toml")
+        .parse()
+        .unwrap();
+
+    vec![
+        Box::new(RevisedRomanization),
+        Box::new(McCuneReischauer::new()),
+        Box::new(McCuneReischauer::ascii(false)),
+        Box::new(Yale::new().periods(true)),
+        Box::new(Yale::new().middle_korean(true)),
+        Box::new(Iso11941::north()),
+        Box::new(Iso11941::south()),
+        Box::new(Dprk::new()),
+        Box::new(Popular::new()),
+        Box::new(Ipa::broad()),
+        Box::new(Ipa::narrow()),
+        Box::new(PhoneSet::x_sampa()),
+        Box::new(scheme),
+    ]
+}
+
+#[test]
+fn test_arbitrary_chars() {
+    for c in all_chars() {
+        if let Ok(s) = Syllable::from_char(c) {
+            assert_eq!(s.as_char(), c);
+        }
+
+        let _ = Choseong::from_compat(c);
+        let _ = Choseong::from_conjoining(c);
+        let _ = Jungseong::from_compat(c);
+        let _ = Jungseong::from_conjoining(c);
+        let _ = Jongseong::from_compat(c);
+        let _ = Jongseong::from_conjoining(c);
+    }
+}
+
+#[test]
+fn test_every_syllable() {
+    let transcribers = transcribers();
+
+    for c in all_syllables() {
+        let mut s = Syllable::from_char(c).unwrap();
+        let (j, k, m) = s.decompose();
+
+        assert_eq!(Syllable::new(j, k, m), s);
+        let _ = j.to_jongseong();
+        let _ = m.map(Jongseong::to_choseong);
+
+        s.set_choseong(j);
+        s.set_jungseong(k);
+        s.set_jongseong(m);
+        assert_eq!(s.as_char(), c);
+
+        let word = Word::new(c.to_string()).unwrap();
+        let _ = word.romanize_syllables();
+        let _ = word.phonemes();
+
+        for t in &transcribers {
+            let _ = word.romanize_with(t.as_ref());
+        }
+    }
+
+    // Runs of syllables exercise the rules across syllable boundaries.
+    let chars: Vec<_> = all_syllables().collect();
+
+    for chunk in chars.chunks(7) {
+        let input: String = chunk.iter().collect();
+
+        for t in &transcribers {
+            let _ = romanize_with(&input, t.as_ref());
+        }
+    }
+}
+
+#[test]
+fn test_arbitrary_strings() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    let transcribers = transcribers();
+    let romanizer = Romanizer::new(RevisedRomanization).threads(2).cache(16);
+    let hangeulizer = Hangeulizer::new().limit(4);
+    let mut index = SearchIndex::new();
+    let mut inputs = Vec::new();
+
+    for _ in 0..300 {
+        let input = rng.string(24);
+
+        for t in &transcribers {
+            let _ = romanize_with(&input, t.as_ref());
+            let _ = romanize_aligned_with(&input, t.as_ref());
+        }
+
+        match Word::new(&input) {
+            Ok(mut word) => {
+                Pronouncer::new(true).pronounce_in_place(&mut word);
+                let _ = word.romanize();
+                let _ = word.romanize_syllables();
+                let _ = word.phonemes();
+                let _ = word.to_string();
+            }
+            Err(e) => assert_eq!(input[e.position()..].chars().next(), Some(e.character())),
+        }
+
+        for _ in Lexer::new(input.chars()) {}
+        let _ = Romanized(&input).to_string();
+        let _ = romanizer.romanize(&input);
+
+        let _ = Ruby::new().render(&input);
+        let _ = Ruby::new()
+            .per_syllable(true)
+            .pronunciation(true)
+            .render(&input);
+        let _ = Ssml::new().render(&input);
+        let _ = romanize_html(&input);
+        let _ = romanize_markdown(&input);
+
+        let _ = SubtitleFormat::detect(&input);
+
+        for &format in &[
+            SubtitleFormat::Srt,
+            SubtitleFormat::WebVtt,
+            SubtitleFormat::Lrc,
+        ] {
+            let _ = Subtitles::new(format).romanize(&input);
+            let _ = Subtitles::new(format)
+                .pronounce(true)
+                .dual(true)
+                .romanize(&input);
+        }
+
+        let mut output = Vec::new();
+        romanize_stream(input.as_bytes(), &mut output).unwrap();
+
+        let _ = hangeulizer.candidates(&input);
+        let _ = hangeulize(&input);
+
+        index.insert(input.as_str());
+        let _ = index.search(&input, 1.0);
+
+        inputs.push(input);
+    }
+
+    let _ = romanize_batch(&inputs.iter().map(String::as_str).collect::<Vec<_>>());
+    let _ = romanizer.romanize_batch(&inputs);
+}